@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::time::Duration;
+
+use advent_of_code_2025::{budget::with_budget, solution::Solution, tenth_day::TenthDay};
+use libfuzzer_sys::fuzz_target;
+
+// Any input is either parsed or rejected with an error, the parser never panics,
+// and part one never panics on what was parsed, the budget keeps large machines short
+fuzz_target!(|input: &str| {
+    if let Ok(machines) = TenthDay::parse(input) {
+        let _ = with_budget(Some(Duration::from_millis(100)), || {
+            TenthDay::part_one(&machines)
+        });
+    }
+});
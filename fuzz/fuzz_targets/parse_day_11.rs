@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2025::{eleventh_day::EleventhDay, solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+// Any input is either parsed or rejected with an error, the parser never panics
+fuzz_target!(|input: &str| {
+    let _ = EleventhDay::parse(input);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::time::Duration;
+
+use advent_of_code_2025::{budget::with_budget, fifth_day::FifthDay, solution::Solution};
+use libfuzzer_sys::fuzz_target;
+
+// Any input is either parsed or rejected with an error, the parser never panics,
+// and part one never panics on what was parsed
+fuzz_target!(|input: &str| {
+    if let Ok(database) = FifthDay::parse(input) {
+        let _ = with_budget(Some(Duration::from_millis(100)), || {
+            FifthDay::part_one(&database)
+        });
+    }
+});
@@ -0,0 +1,168 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Day;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part 1"),
+            Phase::PartTwo => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        Some(Timings {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DayBenchmark {
+    pub day: u8,
+    pub timings: BTreeMap<Phase, Timings>,
+}
+
+fn time<T>(function: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let output = function()?;
+    Ok((output, start.elapsed()))
+}
+
+/// Run every phase of a day n_runs times, each run parses the input again.
+pub fn benchmark_day(day: &Day, input: &str, n_runs: usize) -> Result<DayBenchmark> {
+    let mut samples: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..n_runs {
+        let (parsed_input, parse_duration) = time(|| day.solver.parse(input))?;
+        let (_, part_one_duration) = time(|| day.solver.part_one(&parsed_input))?;
+        let (_, part_two_duration) = time(|| day.solver.part_two(&parsed_input))?;
+        for (phase, duration) in [
+            (Phase::Parse, parse_duration),
+            (Phase::PartOne, part_one_duration),
+            (Phase::PartTwo, part_two_duration),
+        ] {
+            samples.entry(phase).or_default().push(duration);
+        }
+    }
+    Ok(DayBenchmark {
+        day: day.number,
+        timings: samples
+            .into_iter()
+            .filter_map(|(phase, durations)| {
+                Timings::from_samples(durations).map(|timings| (phase, timings))
+            })
+            .collect(),
+    })
+}
+
+/// Median timings of previous benchmarks, in nanoseconds, per day and phase.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u8, BTreeMap<Phase, u128>>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read the baseline {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Could not write the baseline {}", path.display()))
+    }
+
+    pub fn from_benchmarks(benchmarks: &[DayBenchmark]) -> Self {
+        Baseline {
+            days: benchmarks
+                .iter()
+                .map(|benchmark| {
+                    (
+                        benchmark.day,
+                        benchmark
+                            .timings
+                            .iter()
+                            .map(|(phase, timings)| (*phase, timings.median.as_nanos()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.days
+            .get(&day)
+            .and_then(|phases| phases.get(&phase))
+            .map(|&nanos| Duration::from_nanos(nanos as u64))
+    }
+}
+
+// Relative change of the median compared to the baseline, e.g +12.5%
+fn format_change(median: Duration, baseline: Duration) -> String {
+    if baseline.is_zero() {
+        return String::from("n/a");
+    }
+    let change = (median.as_secs_f64() / baseline.as_secs_f64() - 1.) * 100.;
+    format!("{:+.1}%", change)
+}
+
+pub fn format_report(benchmarks: &[DayBenchmark], baseline: Option<&Baseline>) -> String {
+    let mut lines = vec![format!(
+        "{:<5}{:<8}{:>14}{:>14}{:>14}{:>14}",
+        "Day", "Phase", "Min", "Median", "Max", "Baseline"
+    )];
+    for benchmark in benchmarks {
+        for (phase, timings) in benchmark.timings.iter() {
+            let change = baseline
+                .and_then(|baseline| baseline.get(benchmark.day, *phase))
+                .map(|baseline_median| format_change(timings.median, baseline_median))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{:<5}{:<8}{:>14}{:>14}{:>14}{:>14}",
+                benchmark.day,
+                phase.to_string(),
+                format!("{:.2?}", timings.min),
+                format!("{:.2?}", timings.median),
+                format!("{:.2?}", timings.max),
+                change
+            ));
+        }
+    }
+    lines.join("\n")
+}
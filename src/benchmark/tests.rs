@@ -0,0 +1,93 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::{
+    benchmark::{Baseline, DayBenchmark, Phase, Timings, benchmark_day, format_change},
+    registry::get_day,
+};
+
+static FIRST_DAY_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+#[test]
+fn test_timings_from_samples() {
+    let timings = Timings::from_samples(vec![
+        Duration::from_millis(3),
+        Duration::from_millis(1),
+        Duration::from_millis(10),
+    ])
+    .expect("The samples to not be empty");
+    assert_eq!(
+        timings,
+        Timings {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(10),
+        }
+    )
+}
+
+#[test]
+fn test_timings_without_samples() {
+    assert!(Timings::from_samples(Vec::new()).is_none())
+}
+
+#[test]
+fn test_benchmark_day_times_every_phase() {
+    let day = get_day(1).expect("The first day to be registered");
+    let benchmark = benchmark_day(day, FIRST_DAY_INPUT, 3).expect("The benchmark to run");
+    assert_eq!(benchmark.day, 1);
+    assert_eq!(
+        benchmark.timings.keys().copied().collect::<Vec<Phase>>(),
+        vec![Phase::Parse, Phase::PartOne, Phase::PartTwo]
+    );
+}
+
+#[test]
+fn test_baseline_is_built_from_medians() {
+    let timings = Timings {
+        min: Duration::from_nanos(1),
+        median: Duration::from_nanos(5),
+        max: Duration::from_nanos(8),
+    };
+    let benchmark = DayBenchmark {
+        day: 4,
+        timings: BTreeMap::from([(Phase::Parse, timings)]),
+    };
+    let baseline = Baseline::from_benchmarks(&[benchmark]);
+    assert_eq!(baseline.get(4, Phase::Parse), Some(Duration::from_nanos(5)));
+    assert_eq!(baseline.get(4, Phase::PartOne), None);
+    assert_eq!(baseline.get(5, Phase::Parse), None);
+}
+
+#[test]
+fn test_baseline_json_round_trip() {
+    let baseline = Baseline {
+        days: BTreeMap::from([(2, BTreeMap::from([(Phase::PartTwo, 1200)]))]),
+    };
+    let json = serde_json::to_string(&baseline).expect("The baseline to be serialized");
+    assert_eq!(json, r#"{"days":{"2":{"part_two":1200}}}"#);
+    assert_eq!(
+        serde_json::from_str::<Baseline>(&json).expect("The baseline to be deserialized"),
+        baseline
+    );
+}
+
+#[test]
+fn test_format_change() {
+    assert_eq!(
+        format_change(Duration::from_millis(15), Duration::from_millis(10)),
+        "+50.0%"
+    );
+    assert_eq!(
+        format_change(Duration::from_millis(5), Duration::from_millis(10)),
+        "-50.0%"
+    );
+}
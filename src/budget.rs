@@ -0,0 +1,54 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// Error of a solver stopped because its time budget is exhausted.
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+#[error("Timed out, the time budget of {0:.2?} is exhausted")]
+pub struct Timeout(pub Duration);
+
+#[derive(Debug, Clone, Copy)]
+struct Deadline {
+    at: Instant,
+    budget: Duration,
+}
+
+thread_local! {
+    // Deadline of the solver running on the thread, the days run in parallel have their own
+    static DEADLINE: Cell<Option<Deadline>> = const { Cell::new(None) };
+}
+
+// Restores the deadline of the enclosing budget, even when the function panics
+struct DeadlineGuard(Option<Deadline>);
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        DEADLINE.set(self.0);
+    }
+}
+
+/// Run the function with a time budget, without a budget the function is never stopped.
+/// The budget is cooperative: the long running solvers call `check_budget` regularly and stop
+/// with a `Timeout` error once it is exhausted.
+pub fn with_budget<T>(budget: Option<Duration>, function: impl FnOnce() -> T) -> T {
+    let deadline = budget.map(|budget| Deadline {
+        at: Instant::now() + budget,
+        budget,
+    });
+    let _guard = DeadlineGuard(DEADLINE.replace(deadline));
+    function()
+}
+
+/// Check the time budget of the running solver is not exhausted.
+pub fn check_budget() -> Result<(), Timeout> {
+    match DEADLINE.get() {
+        Some(deadline) if Instant::now() >= deadline.at => Err(Timeout(deadline.budget)),
+        _ => Ok(()),
+    }
+}
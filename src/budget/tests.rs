@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::{
+    budget::{Timeout, check_budget, with_budget},
+    registry::get_day,
+    report::RunReport,
+    solution::Part,
+};
+
+#[test]
+fn test_no_budget_is_never_exhausted() {
+    assert_eq!(with_budget(None, check_budget), Ok(()));
+    assert_eq!(check_budget(), Ok(()));
+}
+
+#[test]
+fn test_exhausted_budget() {
+    assert_eq!(
+        with_budget(Some(Duration::ZERO), check_budget),
+        Err(Timeout(Duration::ZERO))
+    );
+    assert_eq!(
+        with_budget(Some(Duration::from_secs(60)), check_budget),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_budget_ends_with_the_function() {
+    let inner_result = with_budget(Some(Duration::from_secs(60)), || {
+        let inner_result = with_budget(Some(Duration::ZERO), check_budget);
+        (inner_result, check_budget())
+    });
+    assert_eq!(inner_result, (Err(Timeout(Duration::ZERO)), Ok(())));
+    assert_eq!(check_budget(), Ok(()));
+}
+
+#[test]
+fn test_solver_out_of_budget_times_out() {
+    let day = get_day(11).expect("The day to be registered");
+    let report = RunReport::new(
+        day,
+        "example",
+        Ok(String::from("you: out")),
+        Part::First,
+        Some(Duration::ZERO),
+    );
+    assert_eq!(
+        report.parts[0].error.as_deref(),
+        Some("Timed out, the time budget of 0.00ns is exhausted")
+    );
+}
@@ -0,0 +1,187 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use advent_of_code_2025::{
+    examples::DEFAULT_EXAMPLES_DIRECTORY,
+    fetcher::{DEFAULT_BASE_URL, SESSION_ENVIRONMENT_VARIABLE},
+    first_day::{DEFAULT_DIAL_SIZE, DEFAULT_STARTING_POSITION, TraceFormat},
+    generator::DEFAULT_SIZE,
+    report::OutputFormat,
+    scaffold::DEFAULT_SOURCE_DIRECTORY,
+    submission::{DEFAULT_COOLDOWN, DEFAULT_HISTORY_PATH},
+    verification::DEFAULT_ANSWERS_PATH,
+    visualization::{DEFAULT_VISUALIZATION_DIRECTORY, Renderer},
+    watch::DEFAULT_POLL_INTERVAL,
+};
+
+/// Advent of code 2025 solutions runner.
+/// Without any subcommand, an interactive menu is displayed to select the day to run.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Directory containing the inputs named day_<day>.txt, defaults to data
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+    /// Session token used to download the missing inputs into the input directory and to
+    /// submit the answers. The inputs are not downloaded without it
+    #[arg(long, global = true, env = SESSION_ENVIRONMENT_VARIABLE, hide_env_values = true)]
+    pub session: Option<String>,
+    /// Base URL of the website the inputs are downloaded from and the answers submitted to
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+    /// Format of the results of the days
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub format: OutputFormat,
+    /// Log the progress of the solutions on stderr, repeat for more details (-v, -vv, -vvv).
+    /// Nothing is logged by default
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+    /// Time budget in seconds of the parsing and of each part of a day, a solution exceeding
+    /// it is stopped and reported as timed out. The solutions are not limited by default
+    #[arg(long, global = true)]
+    pub timeout: Option<u64>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the solution of a single day
+    Run {
+        /// Day to run
+        #[arg(short, long)]
+        day: u8,
+        /// Part of the problem to solve, both parts are solved if not provided
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Path of the input file, or - to read it from stdin.
+        /// Defaults to day_<day>.txt in the input directory
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Draw the state of the day before solving it, frame by frame in the terminal or
+        /// as one SVG file per frame. Only some days can be drawn
+        #[arg(long, value_enum)]
+        visualize: Option<Renderer>,
+        /// Directory of the SVG files of the visualization
+        #[arg(long, default_value = DEFAULT_VISUALIZATION_DIRECTORY)]
+        visualization_dir: PathBuf,
+    },
+    /// Run both parts of a day again every time its input, or one of its examples, changes.
+    /// The solutions are not rebuilt, restart the command after changing them
+    Watch {
+        /// Day to watch
+        #[arg(short, long)]
+        day: u8,
+        /// Path of the input file, defaults to day_<day>.txt in the input directory
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Also check the examples of the day
+        #[arg(long)]
+        examples: bool,
+        /// Directory of the examples, with a day_<day> directory per day
+        #[arg(long, default_value = DEFAULT_EXAMPLES_DIRECTORY)]
+        examples_dir: PathBuf,
+        /// Number of milliseconds between two checks of the watched files
+        #[arg(long, default_value_t = DEFAULT_POLL_INTERVAL.as_millis() as u64)]
+        interval: u64,
+    },
+    /// Run both parts of every registered day in parallel and print a summary
+    RunAll,
+    /// Download the inputs missing from the input directory, the session token is required
+    Fetch {
+        /// Day to download, the input of every registered day is downloaded if not provided
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+    /// Time the parsing and both parts of the days
+    Bench {
+        /// Day to benchmark, every registered day is benchmarked if not provided
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Number of runs of each day
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Baseline file to compare the median timings with
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save the median timings to this baseline file
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+    },
+    /// Compare the answers of every registered day with the known good answers
+    Verify {
+        /// Day to verify, every registered day is verified if not provided
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// TOML file of the expected answers, with a [day_<day>] table per day
+        #[arg(short, long, default_value = DEFAULT_ANSWERS_PATH)]
+        answers: PathBuf,
+    },
+    /// Submit the answer of a part, unless the submission history shows it is not worth it
+    Submit {
+        /// Day of the answer
+        #[arg(short, long)]
+        day: u8,
+        /// Part of the answer
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit, computed from the input of the day if not provided
+        #[arg(short, long)]
+        answer: Option<String>,
+        /// JSON file recording every submitted answer and its verdict
+        #[arg(long, default_value = DEFAULT_HISTORY_PATH)]
+        history: PathBuf,
+        /// Minimal number of seconds between two submissions
+        #[arg(long, default_value_t = DEFAULT_COOLDOWN.as_secs())]
+        cooldown: u64,
+    },
+    /// Generate a random input of a day, to stress the solutions or benchmark them at scale
+    Generate {
+        /// Day of the input
+        #[arg(short, long)]
+        day: u8,
+        /// Seed of the random generator, the same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the input: the number of lines, of items or the side of a grid,
+        /// depending on the day
+        #[arg(long, default_value_t = DEFAULT_SIZE)]
+        size: usize,
+        /// File to write the input to, it is printed if not provided
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Record the state of the dial of day 1 around each rotation, to audit the counts of
+    /// both parts
+    Trace {
+        /// Path of the input file, or - to read it from stdin.
+        /// Defaults to day_1.txt in the input directory
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Number of positions of the dial
+        #[arg(long, default_value_t = DEFAULT_DIAL_SIZE)]
+        size: usize,
+        /// Position of the dial before the first rotation
+        #[arg(long, default_value_t = DEFAULT_STARTING_POSITION)]
+        start: usize,
+        /// Format of the exported trace
+        #[arg(long, value_enum, default_value_t)]
+        export: TraceFormat,
+        /// File to write the trace to, it is printed if not provided
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Generate the module of a new day and register it in the runner
+    NewDay {
+        /// Day to create
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Title of the puzzle
+        #[arg(short, long)]
+        title: String,
+        /// Source directory of the crate, containing lib.rs and registry.rs
+        #[arg(long, default_value = DEFAULT_SOURCE_DIRECTORY)]
+        source_dir: PathBuf,
+    },
+}
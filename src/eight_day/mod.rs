@@ -1,72 +1,122 @@
-use std::fs::read_to_string;
+//! Day 8: Playground, connecting the closest junction boxes into circuits.
+
 use std::hash::Hash;
-use std::{cmp::Ordering, collections::HashMap};
+use std::num::ParseIntError;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+};
 
+use anyhow::{Result, anyhow};
 use itertools::{Itertools, iproduct};
+use rand::RngExt;
+use thiserror::Error;
+
+use crate::{
+    generator::InputRng,
+    parsing::LineError,
+    registry::Day,
+    solution::Solution,
+    visualization::{CANVAS_HEIGHT, CANVAS_WIDTH, Color, Picture, Shape, fit_to_canvas},
+};
 
 #[cfg(test)]
 mod tests;
 
-fn parse_input(input: String) -> Vec<JunctionBox> {
+/// Parse the junction boxes, one `x,y,z` per line, indexed by their line.
+pub fn parse_input(input: String) -> Result<Vec<JunctionBox>, LineError<CoordinatesParsingError>> {
     input
         .lines()
         .enumerate()
-        .map(|(index, string_coordinates)| JunctionBox {
-            index,
-            coordinates: JunctionBoxCoordinates::from_string_tuple(string_coordinates),
+        .map(|(index, string_coordinates)| {
+            Ok(JunctionBox {
+                index,
+                coordinates: JunctionBoxCoordinates::from_string_tuple(string_coordinates)
+                    .map_err(|error| LineError::new(index, string_coordinates, error))?,
+            })
         })
-        .collect::<Vec<JunctionBox>>()
+        .collect()
+}
+
+#[derive(Error, Debug)]
+pub enum CoordinatesParsingError {
+    #[error("Expected 3 comma separated coordinates, got {0}")]
+    InvalidNumberOfCoordinates(usize),
+    #[error("Invalid coordinate")]
+    InvalidCoordinate(#[from] ParseIntError),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ConnectionError {
+    #[error("Only {n_pairs} pairs of junction boxes to make {n_connections} connections")]
+    NotEnoughPairs {
+        n_pairs: usize,
+        n_connections: usize,
+    },
+    #[error("No single circuit connects all junction boxes")]
+    NoSingleCircuit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct JunctionBoxCoordinates {
-    x: usize,
-    y: usize,
-    z: usize,
+pub struct JunctionBoxCoordinates {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
 }
 
 impl JunctionBoxCoordinates {
-    fn distance(&self, other: &Self) -> isize {
+    /// Squared euclidean distance, enough to compare the distances.
+    pub fn distance(&self, other: &Self) -> isize {
         (self.x as isize - other.x as isize).pow(2)
             + (self.y as isize - other.y as isize).pow(2)
             + (self.z as isize - other.z as isize).pow(2)
     }
 
-    fn from_string_tuple(s: &str) -> Self {
-        let split_string = s.split(",").into_iter().collect::<Vec<&str>>();
-        assert_eq!(split_string.len(), 3);
-        JunctionBoxCoordinates {
-            x: split_string[0]
-                .parse()
-                .expect("The x coordinate to be a valid int"),
-            y: split_string[1]
-                .parse()
-                .expect("The y coordinate to be a valid int"),
-            z: split_string[2]
-                .parse()
-                .expect("The z coordinate to be a valid int"),
+    /// Parse coordinates written as `x,y,z`.
+    pub fn from_string_tuple(s: &str) -> Result<Self, CoordinatesParsingError> {
+        let split_string = s.split(",").collect::<Vec<&str>>();
+        if let [x, y, z] = split_string[..] {
+            Ok(JunctionBoxCoordinates {
+                x: x.parse()?,
+                y: y.parse()?,
+                z: z.parse()?,
+            })
+        } else {
+            Err(CoordinatesParsingError::InvalidNumberOfCoordinates(
+                split_string.len(),
+            ))
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct JunctionBox {
-    coordinates: JunctionBoxCoordinates,
-    index: usize,
+pub struct JunctionBox {
+    pub coordinates: JunctionBoxCoordinates,
+    pub index: usize,
+}
+
+/// Connections made between the junction boxes.
+#[derive(Debug)]
+pub struct Connections<'a> {
+    /// Indexes of the boxes of every connection, in the order they were made
+    pub pairs: Vec<(usize, usize)>,
+    /// Last two boxes connected, when they joined every box into a single circuit
+    pub last_merge: Option<(&'a JunctionBox, &'a JunctionBox)>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
-struct Circuit {
-    id: usize,
+pub struct Circuit {
+    pub id: usize,
 }
 
-struct CircuitMapping {
+/// Circuit of every junction box, each box starts in its own circuit.
+pub struct CircuitMapping {
     junction_box_id_to_circuit: HashMap<usize, Circuit>,
     junction_box_id_to_junction_box: HashMap<usize, JunctionBox>,
 }
 
 impl CircuitMapping {
-    fn new(junction_boxes: &Vec<JunctionBox>) -> Self {
+    pub fn new(junction_boxes: &Vec<JunctionBox>) -> Self {
         Self {
             junction_box_id_to_circuit: HashMap::from_iter(junction_boxes.iter().map(
                 |junction_box| {
@@ -86,7 +136,7 @@ impl CircuitMapping {
         }
     }
 
-    fn get_circuit_of_junction_box(&self, junction_box_id: &usize) -> &Circuit {
+    pub fn get_circuit_of_junction_box(&self, junction_box_id: &usize) -> &Circuit {
         self.junction_box_id_to_circuit
             .get(junction_box_id)
             .expect("The mapping to be complete")
@@ -127,45 +177,63 @@ impl CircuitMapping {
             .expect("The mapping to be complete")
     }
 
-    fn build_connections(
+    pub fn build_connections(
         &mut self,
         distance_mapping: &DistanceMapping,
         n_max_connections: Option<usize>,
         // Build the connections over the circuit mapping based on closest distance.
-        // If n_max_connections is Some, stops after n connections and returns None.
+        // If n_max_connections is Some, stops after n connections without a last merge.
         // If n_max_connection is None, break only when there is one single circuit connecting all junction boxes
         // and return the last two junction boxes that were connected.
-    ) -> Option<(&JunctionBox, &JunctionBox)> {
+        // Running out of pairs of junction boxes before that is an error.
+        // The connected pairs are returned in both cases.
+    ) -> Result<Connections<'_>, ConnectionError> {
         let mut distance_iterator = distance_mapping
             .mapping
             .iter()
             .filter(|((k1, k2), _)| k1 != k2)
             .sorted_by_key(|(_, value)| *value);
+        let mut pairs = Vec::new();
         let mut n_connections = 0;
         while match n_max_connections {
             Some(max_connections) => n_connections < max_connections,
             None => true,
         } {
-            let ((idx_a, idx_b), _) = distance_iterator.next().expect(
-                "The distances to not finish before we have the correct number of connections",
-            );
+            let Some(((idx_a, idx_b), _)) = distance_iterator.next() else {
+                // Every pair was connected
+                return Err(match n_max_connections {
+                    Some(max_connections) => ConnectionError::NotEnoughPairs {
+                        n_pairs: n_connections,
+                        n_connections: max_connections,
+                    },
+                    None => ConnectionError::NoSingleCircuit,
+                });
+            };
             let circuit_a = self.get_circuit_of_junction_box(idx_a);
             let circuit_b = self.get_circuit_of_junction_box(idx_b);
             n_connections += 1;
+            pairs.push((*idx_a, *idx_b));
             self.merge_circuits(circuit_a.id, circuit_b.id);
             if self.get_circuits_size().len() == 1 {
-                return Some((self.get_junction_box(idx_a), self.get_junction_box(idx_b)));
+                return Ok(Connections {
+                    pairs,
+                    last_merge: Some((self.get_junction_box(idx_a), self.get_junction_box(idx_b))),
+                });
             }
         }
-        None
+        Ok(Connections {
+            pairs,
+            last_merge: None,
+        })
     }
 
-    fn get_circuits_size(&self) -> HashMap<&Circuit, usize> {
+    /// Number of junction boxes of each circuit.
+    pub fn get_circuits_size(&self) -> HashMap<&Circuit, usize> {
         self.junction_box_id_to_circuit.values().counts()
     }
 }
 
-struct DistanceMapping {
+pub struct DistanceMapping {
     // Mapping of the distances between two junction boxes
     mapping: HashMap<(usize, usize), isize>,
 }
@@ -188,14 +256,12 @@ impl DistanceMapping {
     }
 }
 
-pub fn main() {
-    let input =
-        parse_input(read_to_string("data/day_8.txt").expect("The input file to be readable"));
-    let mut circuit_mapping = CircuitMapping::new(&input);
+/// Distances between every pair of junction boxes.
+pub fn compute_distances(junction_boxes: &[JunctionBox]) -> DistanceMapping {
     let mut distances = DistanceMapping {
         mapping: HashMap::new(),
     };
-    iproduct!(input.iter(), input.iter()).for_each(|(box_a, box_b)| {
+    iproduct!(junction_boxes.iter(), junction_boxes.iter()).for_each(|(box_a, box_b)| {
         if box_a.index != box_b.index {
             distances.set(
                 box_a.index,
@@ -204,24 +270,131 @@ pub fn main() {
             )
         }
     });
-    circuit_mapping.build_connections(&distances, Some(1000));
-    let result_first_part: usize = circuit_mapping
+    distances
+}
+
+pub const N_CONNECTIONS_FIRST_PART: usize = 1000;
+
+/// Random junction boxes with coordinates up to 100000, the size is the number of boxes.
+/// The first part needs at least 46 boxes to make its 1000 connections.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{},{},{}",
+                rng.random_range(0..=100_000),
+                rng.random_range(0..=100_000),
+                rng.random_range(0..=100_000)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Picture of the circuits after the connections of the first part, seen from above: the
+/// boxes are projected on the X and Y axes and the six largest circuits have their own color.
+pub fn visualize(input: &str) -> Result<Vec<Picture>> {
+    let junction_boxes = parse_input(input.to_string())?;
+    let distances = compute_distances(&junction_boxes);
+    let n_connections = N_CONNECTIONS_FIRST_PART.min(distances.mapping.len());
+    let mut circuit_mapping = CircuitMapping::new(&junction_boxes);
+    let pairs = circuit_mapping
+        .build_connections(&distances, Some(n_connections))?
+        .pairs;
+    let circuit_colors = circuit_mapping
         .get_circuits_size()
-        .values()
-        .sorted()
-        .rev()
-        .take(3)
-        .product();
-    println!(
-        "The result of the first part (product of the size of the 3 largest circuits after 1000 connections is : {}",
-        result_first_part
+        .into_iter()
+        .sorted_by_key(|(circuit, size)| (Reverse(*size), circuit.id))
+        .enumerate()
+        .map(|(rank, (circuit, _))| {
+            let color = if rank < 6 {
+                Color::of_index(rank)
+            } else {
+                Color::Gray
+            };
+            (circuit.id, color)
+        })
+        .collect::<HashMap<usize, Color>>();
+    let color_of_box =
+        |index: &usize| circuit_colors[&circuit_mapping.get_circuit_of_junction_box(index).id];
+
+    let points = fit_to_canvas(
+        &junction_boxes
+            .iter()
+            .map(|junction_box| (junction_box.coordinates.x, junction_box.coordinates.y))
+            .collect::<Vec<(usize, usize)>>(),
+        CANVAS_WIDTH,
+        CANVAS_HEIGHT,
     );
-    let result_second_part = circuit_mapping
-        .build_connections(&distances, None)
-        .and_then(|(box_a, box_b)| Some(box_a.coordinates.x * box_b.coordinates.x))
-        .expect("A single circuit to eventually connect all junction boxes");
-    println!(
-        "The result of the second part (product of the X coordinate of the last two junction boxes to be connected is : {}",
-        result_second_part
-    )
+    let point_of_box = junction_boxes
+        .iter()
+        .zip(points)
+        .map(|(junction_box, point)| (junction_box.index, point))
+        .collect::<HashMap<usize, (usize, usize)>>();
+    let mut picture = Picture::new(
+        format!("Circuits after {} connections", n_connections),
+        CANVAS_WIDTH,
+        CANVAS_HEIGHT,
+    );
+    for (index_a, index_b) in &pairs {
+        picture.push(Shape::Line {
+            from: point_of_box[index_a],
+            to: point_of_box[index_b],
+            color: color_of_box(index_a),
+        });
+    }
+    for junction_box in &junction_boxes {
+        picture.push(Shape::Cell {
+            at: point_of_box[&junction_box.index],
+            glyph: 'o',
+            color: color_of_box(&junction_box.index),
+        });
+    }
+    Ok(vec![picture])
 }
+
+/// Part one multiplies the sizes of the 3 largest circuits after 1000 connections,
+/// part two the X coordinates of the last two boxes connected to get a single circuit.
+pub struct EightDay;
+
+impl Solution for EightDay {
+    type Input = Vec<JunctionBox>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input.to_string())?)
+    }
+
+    // Product of the size of the 3 largest circuits after 1000 connections
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        let mut circuit_mapping = CircuitMapping::new(input);
+        circuit_mapping
+            .build_connections(&compute_distances(input), Some(N_CONNECTIONS_FIRST_PART))?;
+        Ok(circuit_mapping
+            .get_circuits_size()
+            .values()
+            .sorted()
+            .rev()
+            .take(3)
+            .product())
+    }
+
+    // Product of the X coordinate of the last two junction boxes to be connected
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        let mut circuit_mapping = CircuitMapping::new(input);
+        circuit_mapping
+            .build_connections(&compute_distances(input), None)?
+            .last_merge
+            .map(|(box_a, box_b)| box_a.coordinates.x * box_b.coordinates.x)
+            .ok_or_else(|| anyhow!("No single circuit connects all junction boxes"))
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 8,
+    title: "Playground",
+    solver: &EightDay,
+    generator: Some(generate_input),
+    visualizer: Some(visualize),
+};
@@ -1,8 +1,16 @@
 use itertools::{Itertools, iproduct};
+use proptest::prelude::*;
+use rand::SeedableRng;
 use std::collections::{HashMap, HashSet};
 
-use crate::eight_day::{
-    Circuit, CircuitMapping, DistanceMapping, JunctionBoxCoordinates, parse_input,
+use crate::{
+    eight_day::{
+        Circuit, CircuitMapping, ConnectionError, DistanceMapping, EightDay, JunctionBox,
+        JunctionBoxCoordinates, compute_distances, generate_input, parse_input, visualize,
+    },
+    generator::InputRng,
+    solution::Solution,
+    visualization::{Point, Shape},
 };
 
 #[test]
@@ -78,7 +86,11 @@ fn test_build_connections() {
             ((3, 4), 3),
         ]),
     };
-    circuit_mapping.build_connections(&distance_mapping, Some(3));
+    let connections = circuit_mapping
+        .build_connections(&distance_mapping, Some(3))
+        .expect("The connections to be made");
+    assert_eq!(connections.pairs, vec![(0, 2), (1, 2), (3, 4)]);
+    assert!(connections.last_merge.is_none());
     assert_eq!(
         circuit_mapping
             .get_circuit_boxes(1)
@@ -119,7 +131,9 @@ fn test_boxes_part_of_the_same_circuit_are_not_connected_again() {
             ((3, 4), 3),
         ]),
     };
-    circuit_mapping.build_connections(&distance_mapping, Some(3));
+    circuit_mapping
+        .build_connections(&distance_mapping, Some(3))
+        .expect("The connections to be made");
     assert_eq!(
         circuit_mapping
             .get_circuit_boxes(1)
@@ -162,7 +176,7 @@ static INPUT: &str = "162,817,812
 
 #[test]
 fn test_example_first_case() {
-    let input = parse_input(INPUT.to_string());
+    let input = parse_input(INPUT.to_string()).expect("The input to be correctly parsed");
     let mut circuit_mapping = CircuitMapping::new(&input);
     let mut distances = DistanceMapping {
         mapping: HashMap::new(),
@@ -178,7 +192,9 @@ fn test_example_first_case() {
         }
     });
 
-    circuit_mapping.build_connections(&distances, Some(10));
+    circuit_mapping
+        .build_connections(&distances, Some(10))
+        .expect("The connections to be made");
 
     let result: usize = circuit_mapping
         .get_circuits_size()
@@ -196,7 +212,7 @@ fn test_example_first_case() {
 
 #[test]
 fn test_example_second_case() {
-    let input = parse_input(INPUT.to_string());
+    let input = parse_input(INPUT.to_string()).expect("The input to be correctly parsed");
     let mut circuit_mapping = CircuitMapping::new(&input);
     let mut distances = DistanceMapping {
         mapping: HashMap::new(),
@@ -214,6 +230,140 @@ fn test_example_second_case() {
 
     let (last_box_a, last_box_b) = circuit_mapping
         .build_connections(&distances, None)
+        .expect("The connections to be made")
+        .last_merge
         .expect("A single circuit to eventually connect all boxes");
     assert_eq!(last_box_a.coordinates.x * last_box_b.coordinates.x, 25272)
 }
+
+#[test]
+fn test_invalid_line_is_reported() {
+    let error = parse_input("1,2,3\n4,5\n6,7,8".to_string()).expect_err("The input to be invalid");
+    assert_eq!(error.line_number, 2);
+    assert_eq!(error.line, "4,5");
+}
+
+// Reference connections with a union find, returns the sorted circuit sizes after the
+// connections and the last pair of boxes merging two circuits
+fn connect_with_union_find(
+    junction_boxes: &[JunctionBox],
+    n_connections: usize,
+) -> (Vec<usize>, Option<(usize, usize)>) {
+    fn root(parents: &[usize], mut index: usize) -> usize {
+        while parents[index] != index {
+            index = parents[index];
+        }
+        index
+    }
+    let pairs = (0..junction_boxes.len())
+        .tuple_combinations()
+        .sorted_by_key(|&(a, b): &(usize, usize)| {
+            junction_boxes[a]
+                .coordinates
+                .distance(&junction_boxes[b].coordinates)
+        })
+        .collect::<Vec<(usize, usize)>>();
+    let mut parents = (0..junction_boxes.len()).collect::<Vec<usize>>();
+    let mut circuit_sizes = Vec::new();
+    let mut last_merge = None;
+    for (n_connected, &(a, b)) in pairs.iter().enumerate() {
+        if n_connected == n_connections {
+            circuit_sizes = (0..parents.len())
+                .map(|index| root(&parents, index))
+                .counts()
+                .into_values()
+                .sorted()
+                .collect();
+        }
+        let (root_a, root_b) = (root(&parents, a), root(&parents, b));
+        if root_a != root_b {
+            parents[root_a] = root_b;
+            last_merge = Some((b, a));
+        }
+    }
+    (circuit_sizes, last_merge)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_connections_match_union_find(
+        (seed, size, n_connections) in (any::<u64>(), 2..25usize).prop_flat_map(|(seed, size)| {
+            (Just(seed), Just(size), 0..size * (size - 1) / 2)
+        })
+    ) {
+        let junction_boxes = parse_input(generate_input(&mut InputRng::seed_from_u64(seed), size))
+            .expect("The junction boxes to be parsed");
+        let distances = junction_boxes
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.coordinates.distance(&b.coordinates))
+            .collect::<Vec<isize>>();
+        // The order of the connections of boxes at the same distance is not defined
+        prop_assume!(distances.iter().all_unique());
+        let (expected_sizes, expected_last_merge) =
+            connect_with_union_find(&junction_boxes, n_connections);
+
+        let mut circuit_mapping = CircuitMapping::new(&junction_boxes);
+        circuit_mapping.build_connections(&compute_distances(&junction_boxes), Some(n_connections))
+            .expect("The connections to be made");
+        let sizes = circuit_mapping
+            .get_circuits_size()
+            .into_values()
+            .sorted()
+            .collect::<Vec<usize>>();
+        prop_assert_eq!(sizes, expected_sizes);
+
+        let mut circuit_mapping = CircuitMapping::new(&junction_boxes);
+        let last_merge = circuit_mapping
+            .build_connections(&compute_distances(&junction_boxes), None)
+            .expect("The connections to be made")
+            .last_merge
+            .map(|(box_a, box_b)| (box_a.index, box_b.index));
+        prop_assert_eq!(last_merge, expected_last_merge);
+    }
+}
+
+#[test]
+fn test_connections_without_pairs_are_an_error() {
+    let junction_boxes = EightDay::parse("162,817,812").expect("The junction box to be parsed");
+    let distances = compute_distances(&junction_boxes);
+    assert_eq!(
+        CircuitMapping::new(&junction_boxes)
+            .build_connections(&distances, Some(10))
+            .err(),
+        Some(ConnectionError::NotEnoughPairs {
+            n_pairs: 0,
+            n_connections: 10
+        })
+    );
+    assert_eq!(
+        CircuitMapping::new(&junction_boxes)
+            .build_connections(&distances, None)
+            .err(),
+        Some(ConnectionError::NoSingleCircuit)
+    );
+}
+
+#[test]
+fn test_parts_without_junction_boxes_are_errors() {
+    let junction_boxes = EightDay::parse("").expect("The empty input to be parsed");
+    assert!(EightDay::part_one(&junction_boxes).is_err());
+    assert!(EightDay::part_two(&junction_boxes).is_err());
+}
+
+#[test]
+fn test_visualization_draws_the_connections_made() {
+    // Every box is in a single circuit after 2 connections, the third pair is not connected
+    let pictures = visualize("0,0,0\n10,0,0\n30,0,0").expect("The input to be drawn");
+    let lines = pictures[0]
+        .shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Line { from, to, .. } => Some((*from, *to)),
+            _ => None,
+        })
+        .collect::<Vec<(Point, Point)>>();
+    assert_eq!(lines, vec![((39, 0), (0, 0)), ((119, 0), (39, 0))]);
+}
@@ -1,19 +1,30 @@
-use anyhow::Result;
-use core::panic;
+//! Day 11: Reactor, counting the paths between the devices of the reactor.
+
+use anyhow::{Result, bail};
+use rand::RngExt;
 use std::{
     collections::{HashMap, HashSet},
-    fs::read_to_string,
     hash::Hash,
 };
 use thiserror::Error;
+use tracing::trace;
+
+use crate::{
+    budget::{Timeout, check_budget},
+    generator::InputRng,
+    parsing::{LineError, parse_lines},
+    registry::Day,
+    solution::Solution,
+};
 
 #[cfg(test)]
 mod tests;
 
+/// A device of the reactor and the devices its outputs are connected to.
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Device {
-    name: String,
-    outputs: HashSet<String>,
+pub struct Device {
+    pub name: String,
+    pub outputs: HashSet<String>,
 }
 
 impl Hash for Device {
@@ -22,27 +33,40 @@ impl Hash for Device {
     }
 }
 
+#[derive(Debug, Error)]
+pub enum DeviceParsingError {
+    #[error("Expected <name>: <outputs>")]
+    MissingOutputs,
+    #[error("The device name is empty")]
+    EmptyName,
+}
+
 impl Device {
-    fn new(input: &str) -> Self {
-        let mut split_input = input.split(": ");
-        let name = split_input
-            .next()
-            .expect("The input to contain a name")
-            .to_string();
-        let outputs = split_input
-            .next()
-            .expect("The input to contain output devices")
+    /// Parse a device written as `aaa: you hhh`.
+    pub fn new(input: &str) -> Result<Self, DeviceParsingError> {
+        let (name, outputs) = input
+            .split_once(": ")
+            .ok_or(DeviceParsingError::MissingOutputs)?;
+        if name.is_empty() {
+            return Err(DeviceParsingError::EmptyName);
+        }
+        let outputs = outputs
             .split(" ")
             .map(String::from)
             .collect::<HashSet<String>>();
-        Device { name, outputs }
+        Ok(Device {
+            name: name.to_string(),
+            outputs,
+        })
     }
 }
 
-fn parse_input(input: String) -> HashMap<String, Device> {
-    let mut devices = input
-        .lines()
-        .map(Device::new)
+/// Parse the devices by name, the devices only appearing as outputs are added without outputs.
+pub fn parse_input(
+    input: String,
+) -> Result<HashMap<String, Device>, LineError<DeviceParsingError>> {
+    let mut devices = parse_lines(&input, Device::new)?
+        .into_iter()
         .map(|device| (device.name.clone(), device))
         .collect::<HashMap<String, Device>>();
     // Append the devices that only have output
@@ -69,11 +93,11 @@ fn parse_input(input: String) -> HashMap<String, Device> {
     output_devices.iter().for_each(|device| {
         devices.insert(device.name.clone(), device.clone());
     });
-    devices
+    Ok(devices)
 }
 
 #[derive(Debug, Error)]
-enum PathFindingError {
+pub enum PathFindingError {
     #[error("No device named {0} found")]
     MissingStartingDevice(String),
     #[error("graph is not a DAG")]
@@ -81,13 +105,16 @@ enum PathFindingError {
 }
 
 #[derive(Debug)]
-enum PathFindingStatus {
+pub enum PathFindingStatus {
     InvalidPathFound,
     ValidPathFound(Vec<HashSet<String>>),
     ClosedLoop,
 }
 
-fn find_paths(
+/// Every path from one device to another going through the required devices, each path
+/// is given as the set of the devices it goes through. The search stops when the time
+/// budget of the run is exhausted.
+pub fn find_paths(
     devices: &HashMap<String, Device>,
     from: &str,
     to: &str,
@@ -95,9 +122,10 @@ fn find_paths(
     required_nodes: &HashSet<&str>,
     // deadends: &mut HashSet<String>,
 ) -> Result<PathFindingStatus> {
+    check_budget()?;
     if existing_path.contains(from) {
         // Then the path has already been explored
-        println!("Found closed loop");
+        trace!(device = from, "Found closed loop");
         Ok(PathFindingStatus::ClosedLoop)
     // } else if deadends.contains(from) {
     //     Ok(PathFindingStatus::DeadendFound(from.to_string()))
@@ -118,7 +146,7 @@ fn find_paths(
 
         let mut result = Vec::new();
         for output in device.outputs.iter() {
-            if let Ok(path_status) = find_paths(
+            match find_paths(
                 &devices,
                 output,
                 to,
@@ -126,14 +154,15 @@ fn find_paths(
                 required_nodes,
                 // deadends,
             ) {
-                match path_status {
-                    PathFindingStatus::ValidPathFound(paths) => {
-                        for path in paths {
-                            result.push(path)
-                        }
+                Ok(PathFindingStatus::ValidPathFound(paths)) => {
+                    for path in paths {
+                        result.push(path)
                     }
-                    _ => {}
                 }
+                // Outputs which are not devices are dead ends, but the whole search stops
+                // once the budget is exhausted
+                Err(error) if error.is::<Timeout>() => return Err(error),
+                _ => {}
             };
         }
         // if result.is_empty() {
@@ -172,7 +201,10 @@ impl Graph {
 }
 
 // Seems like it not acyclic.
-fn topological_sort(devices: &HashMap<String, Device>) -> Result<Vec<Device>, PathFindingError> {
+/// Sort the devices so every device comes before the devices its outputs are connected to.
+pub fn topological_sort(
+    devices: &HashMap<String, Device>,
+) -> Result<Vec<Device>, PathFindingError> {
     let mut graph = Graph::new(devices);
     let mut sorted_devices = Vec::new();
     let mut start_nodes = devices
@@ -210,7 +242,8 @@ fn topological_sort(devices: &HashMap<String, Device>) -> Result<Vec<Device>, Pa
     }
 }
 
-fn find_n_paths(topologically_sorted_nodes: &Vec<Device>, start: &str, out: &str) -> usize {
+/// Number of paths between two devices of a topologically sorted graph.
+pub fn find_n_paths(topologically_sorted_nodes: &Vec<Device>, start: &str, out: &str) -> usize {
     let mut ways: HashMap<String, usize> = HashMap::new();
     ways.insert(start.to_string(), 1);
     for node in topologically_sorted_nodes.iter() {
@@ -227,7 +260,7 @@ fn find_n_paths(topologically_sorted_nodes: &Vec<Device>, start: &str, out: &str
     }
 }
 
-fn find_n_paths_with_2_intermediate_steps(
+pub fn find_n_paths_with_2_intermediate_steps(
     topologically_sorted_nodes: &Vec<Device>,
     start: &str,
     end: &str,
@@ -247,37 +280,97 @@ fn find_n_paths_with_2_intermediate_steps(
     }
 }
 
-pub fn main() {
-    let devices =
-        parse_input(read_to_string("data/day_11.txt").expect("The data to be correctly read"));
-    let Ok(PathFindingStatus::ValidPathFound(first_problem_paths)) = find_paths(
-        &devices,
-        "you",
-        "out",
-        HashSet::new(),
-        &HashSet::new(),
-        // &mut HashSet::new(),
-    ) else {
-        panic!("Expected a valid search result")
-    };
-    println!(
-        "The solution to the first problem (number of path between you and out): {}",
-        first_problem_paths.iter().count()
-    );
+// Random device name of 3 letters, not used yet
+fn random_name(rng: &mut InputRng, used_names: &mut HashSet<String>) -> String {
+    loop {
+        let name = (0..3)
+            .map(|_| char::from(b'a' + rng.random_range(0..26)))
+            .collect::<String>();
+        if used_names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Random graph of devices in layers, each device is connected to devices of the next layer
+/// and the last layer to `out`. `svr` is in the first layer, `fft` and `dac` in between and
+/// `you` close to the last layer, so the paths from `you` can be listed one by one. The size
+/// is the number of devices, up to 10000 as the names only have 3 letters.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    let size = size.min(10_000);
+    let n_layers = size.isqrt().max(4);
+    let layer_width = (size / n_layers).max(2);
+    let mut used_names = HashSet::from(["you", "out", "svr", "fft", "dac"].map(String::from));
+    let mut layers = (0..n_layers)
+        .map(|_| {
+            (0..layer_width)
+                .map(|_| random_name(rng, &mut used_names))
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<Vec<String>>>();
+    layers[0][0] = String::from("svr");
+    layers[n_layers / 3][0] = String::from("fft");
+    layers[2 * n_layers / 3][0] = String::from("dac");
+    layers[n_layers.saturating_sub(8).max(1)][layer_width - 1] = String::from("you");
+    let mut lines = Vec::new();
+    for (layer, next_layer) in layers.iter().zip(layers.iter().skip(1)) {
+        for (index, device) in layer.iter().enumerate() {
+            // Each device reaches the device of the next layer at the same index, so every
+            // device is reachable, and sometimes another one
+            let mut outputs = vec![next_layer[index].as_str()];
+            if rng.random_bool(0.3) {
+                let other = next_layer[rng.random_range(0..layer_width)].as_str();
+                if other != outputs[0] {
+                    outputs.push(other);
+                }
+            }
+            lines.push(format!("{}: {}", device, outputs.join(" ")));
+        }
+    }
+    for device in layers.last().into_iter().flatten() {
+        lines.push(format!("{}: out", device));
+    }
+    lines.join("\n")
+}
+
+/// Part one counts the paths from `you` to `out`, part two the paths from `svr` to `out`
+/// going through both `fft` and `dac`.
+pub struct EleventhDay;
 
-    let topologically_sorted_graph =
-        topological_sort(&devices).expect("The nodes to be topologically sorted");
-    let n_total_paths = find_n_paths(&topologically_sorted_graph, "svr", "out");
-    println!("Number of total paths from svr to out {}", n_total_paths);
-    let second_problem_result = find_n_paths_with_2_intermediate_steps(
-        &topologically_sorted_graph,
-        "svr",
-        "out",
-        "fft",
-        "dac",
-    );
-    println!(
-        "The result to the second problem (number of points from svr to out passing by fft and dac) is {}",
-        second_problem_result
-    )
+impl Solution for EleventhDay {
+    type Input = HashMap<String, Device>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input.to_string())?)
+    }
+
+    // Number of paths between you and out
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        match find_paths(input, "you", "out", HashSet::new(), &HashSet::new())? {
+            PathFindingStatus::ValidPathFound(paths) => Ok(paths.len()),
+            status => bail!("Expected a valid search result, got {:?}", status),
+        }
+    }
+
+    // Number of paths from svr to out passing by fft and dac
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        let topologically_sorted_graph = topological_sort(input)?;
+        Ok(find_n_paths_with_2_intermediate_steps(
+            &topologically_sorted_graph,
+            "svr",
+            "out",
+            "fft",
+            "dac",
+        ))
+    }
 }
+
+pub const DAY: Day = Day {
+    number: 11,
+    title: "Reactor",
+    solver: &EleventhDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
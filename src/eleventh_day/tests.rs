@@ -1,8 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::eleventh_day::{
-    PathFindingStatus, find_n_paths_with_2_intermediate_steps, find_paths, parse_input,
-    topological_sort,
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+use crate::{
+    eleventh_day::{
+        Device, EleventhDay, PathFindingStatus, find_n_paths_with_2_intermediate_steps, find_paths,
+        generate_input, parse_input, topological_sort,
+    },
+    generator::InputRng,
+    solution::Solution,
 };
 
 static INPUT: &str = "aaa: you hhh
@@ -19,7 +26,7 @@ iii: out
 
 #[test]
 fn test_first_problem_example() {
-    let devices = parse_input(INPUT.to_string());
+    let devices = parse_input(INPUT.to_string()).expect("The input to be correctly parsed");
     let result = find_paths(
         &devices,
         "you",
@@ -51,7 +58,8 @@ hhh: out";
 
 #[test]
 fn test_second_problem() {
-    let devices = parse_input(INPUT_SECOND_PROBLEM.to_string());
+    let devices =
+        parse_input(INPUT_SECOND_PROBLEM.to_string()).expect("The input to be correctly parsed");
     let topologically_sorted_nodes =
         topological_sort(&devices).expect("The nodes to be topologically sorted");
     let result = find_n_paths_with_2_intermediate_steps(
@@ -63,3 +71,53 @@ fn test_second_problem() {
     );
     assert_eq!(result, 2)
 }
+
+#[test]
+fn test_device_without_outputs_is_rejected() {
+    let error = parse_input("you: out\nbbb".to_string()).expect_err("The input to be invalid");
+    assert_eq!(error.line_number, 2);
+    assert_eq!(error.line, "bbb");
+}
+
+// Reference number of paths, following every path one by one and counting the ones going
+// through all the required devices
+fn count_paths_one_by_one(
+    devices: &HashMap<String, Device>,
+    from: &str,
+    to: &str,
+    required: &[&str],
+) -> usize {
+    if from == to {
+        return usize::from(required.is_empty());
+    }
+    let required = required
+        .iter()
+        .copied()
+        .filter(|&device| device != from)
+        .collect::<Vec<&str>>();
+    devices.get(from).map_or(0, |device| {
+        device
+            .outputs
+            .iter()
+            .map(|output| count_paths_one_by_one(devices, output, to, &required))
+            .sum()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_paths_match_paths_one_by_one(seed in any::<u64>(), size in 1..60usize) {
+        let devices = EleventhDay::parse(&generate_input(&mut InputRng::seed_from_u64(seed), size))
+            .expect("The devices to be parsed");
+        prop_assert_eq!(
+            EleventhDay::part_one(&devices).expect("The paths from you to be found"),
+            count_paths_one_by_one(&devices, "you", "out", &[])
+        );
+        prop_assert_eq!(
+            EleventhDay::part_two(&devices).expect("The paths from svr to be counted"),
+            count_paths_one_by_one(&devices, "svr", "out", &["fft", "dac"])
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::{registry::Day, solution::Answer, verification::ExpectedAnswers};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_EXAMPLES_DIRECTORY: &str = "examples";
+
+/// Example input of a day, stored in `examples/day_<number>/<name>.txt` next to a
+/// `<name>.toml` sidecar holding the expected answers:
+/// ```toml
+/// part_one = 3
+/// part_two = 6
+/// ```
+/// A part without an expected answer is not checked, for examples only valid for one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub day: u8,
+    pub name: String,
+    pub input_path: PathBuf,
+    pub expected: ExpectedAnswers,
+}
+
+impl Example {
+    pub fn load(day: u8, input_path: &Path) -> Result<Self> {
+        let name = input_path
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+            .with_context(|| format!("Invalid example path {}", input_path.display()))?;
+        let sidecar_path = input_path.with_extension("toml");
+        let sidecar = fs::read_to_string(&sidecar_path).with_context(|| {
+            format!(
+                "Could not read the expected answers {} of the example",
+                sidecar_path.display()
+            )
+        })?;
+        let expected: ExpectedAnswers = toml::from_str(&sidecar)
+            .with_context(|| format!("Invalid expected answers {}", sidecar_path.display()))?;
+        if expected.part_one.is_none() && expected.part_two.is_none() {
+            bail!(
+                "{} does not have any expected answer",
+                sidecar_path.display()
+            );
+        }
+        Ok(Example {
+            day,
+            name,
+            input_path: input_path.to_path_buf(),
+            expected,
+        })
+    }
+}
+
+/// Examples of a day, sorted by name. A day without an example directory has no example.
+pub fn discover_examples(directory: &Path, day: u8) -> Result<Vec<Example>> {
+    let day_directory = directory.join(format!("day_{}", day));
+    if !day_directory.exists() {
+        return Ok(Vec::new());
+    }
+    let mut input_paths = fs::read_dir(&day_directory)
+        .with_context(|| format!("Could not read the directory {}", day_directory.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    input_paths.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+    input_paths.sort();
+    input_paths
+        .iter()
+        .map(|input_path| Example::load(day, input_path))
+        .collect()
+}
+
+fn check_part(part: u8, expected: &Answer, actual: Result<Answer>) -> Result<()> {
+    let actual = actual.with_context(|| format!("Part {} failed", part))?;
+    if *expected != actual {
+        bail!("Part {}: expected {}, got {}", part, expected, actual);
+    }
+    Ok(())
+}
+
+/// Solve the example and compare the answers with the expected ones.
+pub fn check_example(day: &Day, example: &Example) -> Result<()> {
+    let input = fs::read_to_string(&example.input_path).with_context(|| {
+        format!(
+            "Could not read the example input {}",
+            example.input_path.display()
+        )
+    })?;
+    let parsed_input = day.solver.parse(&input)?;
+    if let Some(expected) = &example.expected.part_one {
+        check_part(1, expected, day.solver.part_one(&parsed_input))?;
+    }
+    if let Some(expected) = &example.expected.part_two {
+        check_part(2, expected, day.solver.part_two(&parsed_input))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use std::{env::temp_dir, fs, path::Path};
+
+use crate::{
+    examples::{Example, check_example, discover_examples},
+    registry::get_day,
+    solution::Answer,
+    verification::ExpectedAnswers,
+};
+
+static FIRST_DAY_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+fn write_example(directory: &Path, name: &str, input: &str, sidecar: &str) {
+    fs::create_dir_all(directory).expect("The example directory to be created");
+    fs::write(directory.join(format!("{}.txt", name)), input).expect("The input to be written");
+    fs::write(directory.join(format!("{}.toml", name)), sidecar)
+        .expect("The sidecar to be written");
+}
+
+#[test]
+fn test_day_without_examples() {
+    let examples = discover_examples(Path::new("examples/missing_directory"), 1);
+    assert_eq!(examples.expect("The examples to be discovered"), Vec::new())
+}
+
+#[test]
+fn test_discover_examples() {
+    let directory = temp_dir().join("advent_of_code_2025_examples_test");
+    let _ = fs::remove_dir_all(&directory);
+    let day_directory = directory.join("day_1");
+    write_example(&day_directory, "second", "L50", "part_two = 1");
+    write_example(&day_directory, "first", "R50", "part_one = 1\npart_two = 1");
+    fs::write(day_directory.join("notes.md"), "Not an example").expect("The notes to be written");
+
+    let examples = discover_examples(&directory, 1);
+    fs::remove_dir_all(&directory).expect("The examples directory to be removed");
+
+    assert_eq!(
+        examples.expect("The examples to be discovered"),
+        vec![
+            Example {
+                day: 1,
+                name: String::from("first"),
+                input_path: day_directory.join("first.txt"),
+                expected: ExpectedAnswers {
+                    part_one: Some(Answer::Number(1)),
+                    part_two: Some(Answer::Number(1)),
+                },
+            },
+            Example {
+                day: 1,
+                name: String::from("second"),
+                input_path: day_directory.join("second.txt"),
+                expected: ExpectedAnswers {
+                    part_one: None,
+                    part_two: Some(Answer::Number(1)),
+                },
+            },
+        ]
+    )
+}
+
+#[test]
+fn test_example_without_sidecar_fails() {
+    let directory = temp_dir().join("advent_of_code_2025_examples_without_sidecar_test");
+    let _ = fs::remove_dir_all(&directory);
+    let day_directory = directory.join("day_1");
+    fs::create_dir_all(&day_directory).expect("The example directory to be created");
+    fs::write(day_directory.join("example.txt"), "L50").expect("The input to be written");
+
+    let examples = discover_examples(&directory, 1);
+    fs::remove_dir_all(&directory).expect("The examples directory to be removed");
+
+    assert!(examples.is_err())
+}
+
+#[test]
+fn test_sidecar_without_answer_fails() {
+    let directory = temp_dir().join("advent_of_code_2025_examples_without_answer_test");
+    let _ = fs::remove_dir_all(&directory);
+    write_example(&directory.join("day_1"), "example", "L50", "");
+
+    let examples = discover_examples(&directory, 1);
+    fs::remove_dir_all(&directory).expect("The examples directory to be removed");
+
+    assert!(examples.is_err())
+}
+
+#[test]
+fn test_check_example() {
+    let directory = temp_dir().join("advent_of_code_2025_check_example_test");
+    let _ = fs::remove_dir_all(&directory);
+    write_example(&directory, "right", FIRST_DAY_INPUT, "");
+    write_example(&directory, "wrong", FIRST_DAY_INPUT, "");
+    let day = get_day(1).expect("The day to be registered");
+    let example = |name: &str, part_one: usize, part_two: Option<usize>| Example {
+        day: 1,
+        name: name.to_string(),
+        input_path: directory.join(format!("{}.txt", name)),
+        expected: ExpectedAnswers {
+            part_one: Some(Answer::Number(part_one)),
+            part_two: part_two.map(Answer::Number),
+        },
+    };
+
+    let right = check_example(day, &example("right", 3, Some(6)));
+    let only_first_part = check_example(day, &example("right", 3, None));
+    let wrong = check_example(day, &example("wrong", 3, Some(7)));
+    fs::remove_dir_all(&directory).expect("The examples directory to be removed");
+
+    assert!(right.is_ok());
+    assert!(only_first_part.is_ok());
+    assert_eq!(
+        wrong.map_err(|error| error.to_string()),
+        Err(String::from("Part 2: expected 7, got 6"))
+    );
+}
@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2025";
+/// Environment variable holding the session token, the value of the `session` cookie of the website
+pub const SESSION_ENVIRONMENT_VARIABLE: &str = "AOC_SESSION";
+/// Identifies this tool in the requests to the website, as asked by its author
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Downloads the puzzle inputs from `<base_url>/day/<day>/input`, authenticated by the
+/// session token. The base URL can point to any server exposing the same routes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFetcher {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl InputFetcher {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        InputFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!("{}/day/{}/input", self.base_url, day)
+    }
+
+    pub fn fetch(&self, day: u8) -> Result<String> {
+        let url = self.input_url(day);
+        ureq::get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .with_context(|| format!("Could not download the input of day {} from {}", day, url))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{fetcher::InputFetcher, test_server::TestServer};
+
+#[test]
+fn test_input_url_ignores_trailing_slash() {
+    let fetcher = InputFetcher::new("http://localhost:8080/2025/", "token");
+    assert_eq!(
+        fetcher.input_url(3),
+        "http://localhost:8080/2025/day/3/input"
+    )
+}
+
+#[test]
+fn test_fetch_sends_the_session_cookie() {
+    let server = TestServer::start(200, "L68\nL30\n");
+    let fetcher = InputFetcher::new(&server.url, "secret");
+    let input = fetcher.fetch(1).expect("The input to be downloaded");
+    assert_eq!(input, "L68\nL30\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/day/1/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+}
+
+#[test]
+fn test_fetch_fails_on_error_status() {
+    let server = TestServer::start(404, "Please don't repeatedly request this endpoint");
+    let fetcher = InputFetcher::new(&server.url, "secret");
+    let error = fetcher.fetch(25).expect_err("The download to fail");
+    assert!(format!("{:#}", error).contains("404"));
+}
@@ -1,33 +1,55 @@
+//! Day 5: Cafeteria, checking which ingredients are fresh.
+
 use anyhow::Result;
-use std::fs;
+use rand::{RngExt, seq::IndexedRandom};
+use std::{num::ParseIntError, ops::RangeInclusive};
+use thiserror::Error;
 
 #[cfg(test)]
 mod tests;
 
-use crate::second_day::InputIdRange;
+use crate::{
+    generator::InputRng,
+    parsing::LineError,
+    registry::Day,
+    second_day::{InputIdRange, InvalidInputError},
+    solution::Solution,
+};
 
+/// Inclusive range of fresh ingredient ids.
 #[derive(PartialEq, PartialOrd, Eq, Ord, Clone, Debug)]
-struct ProcessingIdRange {
-    start: usize,
-    end: usize,
+pub struct ProcessingIdRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Error, Debug)]
+pub enum IngredientDatabaseParsingError {
+    #[error("Invalid fresh ingredient id range")]
+    InvalidRange(#[from] InvalidInputError),
+    #[error("Invalid ingredient id")]
+    InvalidId(#[from] ParseIntError),
 }
 
-struct IngredientDatabase {
-    id_ranges: Vec<ProcessingIdRange>,
-    ids: Vec<usize>,
+/// Ranges of fresh ingredient ids and the available ingredient ids.
+#[derive(Debug)]
+pub struct IngredientDatabase {
+    pub id_ranges: Vec<ProcessingIdRange>,
+    pub ids: Vec<usize>,
 }
 
 impl IngredientDatabase {
-    fn from_path(path: &str) -> Result<Self> {
+    /// Parse the ranges, one per line, then the ids after a blank line.
+    pub fn new(input: &str) -> Result<Self, LineError<IngredientDatabaseParsingError>> {
         let mut id_ranges = Vec::new();
         let mut ids = Vec::new();
 
-        let mut range_closure = |line: &str| -> Result<()> {
+        let mut range_closure = |line: &str| -> Result<(), IngredientDatabaseParsingError> {
             let input_range = InputIdRange::new(line)?;
             id_ranges.push(ProcessingIdRange::from(input_range));
             Ok(())
         };
-        let mut id_closure = |line: &str| -> Result<()> {
+        let mut id_closure = |line: &str| -> Result<(), IngredientDatabaseParsingError> {
             let id = line.parse::<usize>()?;
             ids.push(id);
             Ok(())
@@ -39,18 +61,16 @@ impl IngredientDatabase {
         }
 
         let mut processing_method = ProcessingType::Range;
-        for line in fs::read_to_string(path)
-            .expect("The input to be correctly read")
-            .lines()
-        {
+        for (index, line) in input.lines().enumerate() {
             if line.is_empty() {
                 processing_method = ProcessingType::Id;
                 continue;
             };
             match processing_method {
-                ProcessingType::Range => range_closure(line)?,
-                ProcessingType::Id => id_closure(line)?,
-            };
+                ProcessingType::Range => range_closure(line),
+                ProcessingType::Id => id_closure(line),
+            }
+            .map_err(|error| LineError::new(index, line, error))?;
         }
         Ok(IngredientDatabase { id_ranges, ids })
     }
@@ -65,13 +85,14 @@ impl From<InputIdRange> for ProcessingIdRange {
     }
 }
 
-struct SpoiledAndFreshIngredients {
-    #[allow(dead_code)]
-    spoiled: Vec<usize>,
-    fresh: Vec<usize>,
+#[derive(Debug)]
+pub struct SpoiledAndFreshIngredients {
+    pub spoiled: Vec<usize>,
+    pub fresh: Vec<usize>,
 }
 
-fn separate_spoiled_and_fresh_ingredients(
+/// Sort the available ingredients depending on whether they are in a fresh range.
+pub fn separate_spoiled_and_fresh_ingredients(
     database: &IngredientDatabase,
 ) -> Result<SpoiledAndFreshIngredients> {
     let mut fresh = Vec::new();
@@ -108,40 +129,90 @@ fn separate_spoiled_and_fresh_ingredients(
     Ok(SpoiledAndFreshIngredients { spoiled, fresh })
 }
 
-fn count_number_of_fresh_ingredients(fresh_ingredient_ranges: &Vec<ProcessingIdRange>) -> usize {
+/// Number of ids covered by the ranges, overlapping ranges are only counted once.
+pub fn count_number_of_fresh_ingredients(
+    fresh_ingredient_ranges: &Vec<ProcessingIdRange>,
+) -> usize {
     let mut n_fresh_ingredients = 0;
     let mut ranges = fresh_ingredient_ranges.clone();
     ranges.sort();
 
     let mut iter_ingredients_range = ranges.into_iter();
-    let mut previous_range = ProcessingIdRange { start: 0, end: 0 };
+    // No previous range for the first one, a range ending at 0 would hide a range of id 0
+    let mut previous_range: Option<ProcessingIdRange> = None;
     let mut current_range = iter_ingredients_range.next();
     while let Some(valid_current_range) = current_range {
-        if previous_range.end >= valid_current_range.end {
+        let previous_end = previous_range.as_ref().map(|range| range.end);
+        if previous_end.is_some_and(|end| end >= valid_current_range.end) {
             // current_range is contained within previous_range
             current_range = iter_ingredients_range.next();
             continue;
-        } else if previous_range.end >= valid_current_range.start {
+        } else if let Some(end) = previous_end.filter(|&end| end >= valid_current_range.start) {
             let valid_current_range = ProcessingIdRange {
-                start: previous_range.end + 1,
+                start: end + 1,
                 end: valid_current_range.end,
             };
             n_fresh_ingredients += &valid_current_range.end - &valid_current_range.start + 1;
-            previous_range = valid_current_range;
+            previous_range = Some(valid_current_range);
         } else {
             n_fresh_ingredients += &valid_current_range.end - &valid_current_range.start + 1;
-            previous_range = valid_current_range;
+            previous_range = Some(valid_current_range);
         }
         current_range = iter_ingredients_range.next();
     }
     n_fresh_ingredients
 }
 
-pub fn main() {
-    let database =
-        IngredientDatabase::from_path("data/day_5.txt").expect("Input to be correctly processed");
-    let ingredients = separate_spoiled_and_fresh_ingredients(&database)
-        .expect("The ingredients to be correctly separated");
-    dbg!(ingredients.fresh.len());
-    dbg!(count_number_of_fresh_ingredients(&database.id_ranges));
+/// Random database of ids up to 10^12, the size is both the number of fresh ranges and of
+/// available ingredients.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    const MAXIMUM_ID: usize = 1_000_000_000_000;
+    let ranges = (0..size)
+        .map(|_| {
+            let start = rng.random_range(1..MAXIMUM_ID);
+            start..=start + rng.random_range(0..MAXIMUM_ID / 1000)
+        })
+        .collect::<Vec<RangeInclusive<usize>>>();
+    // Half of the ingredients are picked in a range, as random ids are rarely fresh
+    let ids = (0..size)
+        .map(|_| match ranges.choose(rng) {
+            Some(range) if rng.random_bool(0.5) => rng.random_range(range.clone()),
+            _ => rng.random_range(1..=MAXIMUM_ID),
+        })
+        .map(|id| id.to_string())
+        .collect::<Vec<String>>();
+    let ranges = ranges
+        .iter()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect::<Vec<String>>();
+    format!("{}\n\n{}", ranges.join("\n"), ids.join("\n"))
 }
+
+/// Part one counts the fresh available ingredients, part two every fresh id.
+pub struct FifthDay;
+
+impl Solution for FifthDay {
+    type Input = IngredientDatabase;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(IngredientDatabase::new(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(separate_spoiled_and_fresh_ingredients(input)?.fresh.len())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        Ok(count_number_of_fresh_ingredients(&input.id_ranges))
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 5,
+    title: "Cafeteria",
+    solver: &FifthDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
@@ -1,4 +1,14 @@
-use crate::fifth_day::{ProcessingIdRange, count_number_of_fresh_ingredients};
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+use crate::{
+    fifth_day::{
+        IngredientDatabase, IngredientDatabaseParsingError, ProcessingIdRange,
+        count_number_of_fresh_ingredients, separate_spoiled_and_fresh_ingredients,
+    },
+    second_day::InvalidInputError,
+};
 
 #[test]
 fn test_fresh_ingredients() {
@@ -44,3 +54,68 @@ fn test_contained_range_ending_on_same_value() {
     ];
     assert_eq!(count_number_of_fresh_ingredients(&ranges), 6)
 }
+
+#[test]
+fn test_range_of_id_zero() {
+    let ranges = vec![ProcessingIdRange { start: 0, end: 0 }];
+    assert_eq!(count_number_of_fresh_ingredients(&ranges), 1)
+}
+
+#[test]
+fn test_range_starting_at_zero_followed_by_other_ranges() {
+    let ranges = vec![
+        ProcessingIdRange { start: 0, end: 3 },
+        ProcessingIdRange { start: 2, end: 4 },
+        ProcessingIdRange { start: 8, end: 8 },
+    ];
+    assert_eq!(count_number_of_fresh_ingredients(&ranges), 6)
+}
+
+fn id_range() -> impl Strategy<Value = ProcessingIdRange> {
+    (0..200usize, 0..20usize).prop_map(|(start, length)| ProcessingIdRange {
+        start,
+        end: start + length,
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_fresh_ingredients_match_ranges_lookup(
+        id_ranges in prop::collection::vec(id_range(), 0..10),
+        ids in prop::collection::vec(0..250usize, 0..20),
+    ) {
+        let is_fresh = |id: &usize| id_ranges.iter().any(|range| (range.start..=range.end).contains(id));
+        let mut expected_fresh = ids.iter().copied().filter(is_fresh).collect::<Vec<usize>>();
+        expected_fresh.sort();
+        let database = IngredientDatabase { id_ranges: id_ranges.clone(), ids };
+
+        let ingredients = separate_spoiled_and_fresh_ingredients(&database)
+            .expect("The ingredients to be separated");
+        prop_assert_eq!(ingredients.fresh, expected_fresh);
+        prop_assert!(ingredients.spoiled.iter().all(|id| !is_fresh(id)));
+    }
+
+    #[test]
+    fn test_number_of_fresh_ingredients_matches_enumerated_ids(
+        id_ranges in prop::collection::vec(id_range(), 0..10)
+    ) {
+        let fresh_ids = id_ranges
+            .iter()
+            .flat_map(|range| range.start..=range.end)
+            .collect::<HashSet<usize>>();
+        prop_assert_eq!(count_number_of_fresh_ingredients(&id_ranges), fresh_ids.len());
+    }
+}
+
+#[test]
+fn test_reversed_range_is_rejected() {
+    let error = IngredientDatabase::new("3-1\n\n2\n").expect_err("The range to be rejected");
+    assert_eq!(error.line_number, 1);
+    assert!(matches!(
+        error.error,
+        IngredientDatabaseParsingError::InvalidRange(InvalidInputError::ReversedRange {
+            start: 3,
+            end: 1
+        })
+    ));
+}
@@ -1,39 +1,206 @@
+//! Day 1: Secret Entrance, a safe dial rotated by a list of instructions.
+
 use anyhow::Result;
-use std::{fs, num::ParseIntError};
+use clap::ValueEnum;
+use itertools::Itertools;
+use rand::RngExt;
+use serde::Serialize;
+use std::{
+    fmt::Write as _,
+    iter::{self, Peekable},
+    str::Chars,
+};
 use thiserror::Error;
 
-enum Direction {
+use crate::{generator::InputRng, registry::Day, solution::Solution};
+
+/// Direction of a rotation of the dial, towards the lower or the higher numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Direction {
+    #[serde(rename = "L")]
     Left,
+    #[serde(rename = "R")]
     Right,
 }
 
+impl Direction {
+    fn letter(&self) -> char {
+        match self {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
 
-#[derive(Error, Debug)]
-enum InstructionParsingError {
-    #[error("Invalid direction, expected L or R, got {0}")]
-    InvalidDirection(String),
-    #[error("Invalid distance")]
-    InvalidDistance(#[from] ParseIntError),
+/// Largest number of repetitions of an instruction, as every repetition is a rotation of
+/// the dial.
+pub const MAXIMUM_MULTIPLIER: usize = 10_000;
+// Start of a comment, up to the end of the line
+const COMMENT_START: char = '#';
+
+/// Problem of an instruction, without its position in the input.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum InstructionErrorKind {
+    #[error("Invalid direction, expected L, R or =, got {0:?}")]
+    InvalidDirection(char),
+    #[error("Expected a number")]
+    MissingNumber,
+    #[error("Number {0} is larger than the supported maximum of {max}", max = u64::MAX)]
+    NumberOutOfRange(String),
+    #[error("Expected x after the multiplier")]
+    MissingMultiplierSign,
+    #[error("Invalid multiplier {0}, expected 1 to {MAXIMUM_MULTIPLIER}")]
+    InvalidMultiplier(String),
+    #[error("Unexpected {0:?} after the instruction")]
+    UnexpectedCharacter(char),
+    #[error("Expected L, R or = after the multiplier")]
+    MissingMovement,
+    #[error("Empty instruction")]
+    EmptyInstruction,
+}
+
+/// Error of an instruction with the line it is on, at a line number and a column both
+/// starting at 1.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Invalid line {line_number}: {line:?}: {kind} at column {column}")]
+pub struct InstructionParsingError {
+    pub line_number: usize,
+    pub column: usize,
+    pub line: String,
+    pub kind: InstructionErrorKind,
+}
+
+// Characters of a line of the input, keeping track of the column of the next one. The
+// whitespace and the comments are only skipped by a cursor over the extended grammar
+struct LineCursor<'a> {
+    line_number: usize,
+    line: &'a str,
+    column: usize,
+    characters: Peekable<Chars<'a>>,
+    extended: bool,
+}
+
+impl<'a> LineCursor<'a> {
+    fn new(line_number: usize, line: &'a str, extended: bool) -> Self {
+        LineCursor {
+            line_number,
+            line,
+            column: 1,
+            characters: line.chars().peekable(),
+            extended,
+        }
+    }
+
+    fn error_at(&self, column: usize, kind: InstructionErrorKind) -> InstructionParsingError {
+        InstructionParsingError {
+            line_number: self.line_number,
+            column,
+            line: self.line.to_string(),
+            kind,
+        }
+    }
+
+    // Error at the column of the next character
+    fn error(&self, kind: InstructionErrorKind) -> InstructionParsingError {
+        self.error_at(self.column, kind)
+    }
+
+    // Next character that is neither whitespace nor part of a comment
+    fn peek_token(&mut self) -> Option<char> {
+        if !self.extended {
+            return self.characters.peek().copied();
+        }
+        while self
+            .characters
+            .next_if(|character| character.is_whitespace())
+            .is_some()
+        {
+            self.column += 1;
+        }
+        self.characters
+            .peek()
+            .copied()
+            .filter(|&character| character != COMMENT_START)
+    }
+
+    fn next_token(&mut self) -> Option<char> {
+        let character = self.peek_token()?;
+        self.characters.next();
+        self.column += 1;
+        Some(character)
+    }
+
+    // Digits starting at the next token, with the column of the first one
+    fn digits(&mut self) -> Result<(usize, String), InstructionParsingError> {
+        if !self
+            .peek_token()
+            .is_some_and(|character| character.is_ascii_digit())
+        {
+            return Err(self.error(InstructionErrorKind::MissingNumber));
+        }
+        let column = self.column;
+        let mut digits = String::new();
+        while let Some(digit) = self.characters.next_if(char::is_ascii_digit) {
+            digits.push(digit);
+            self.column += 1;
+        }
+        Ok((column, digits))
+    }
+
+    fn number(&mut self) -> Result<u64, InstructionParsingError> {
+        let (column, digits) = self.digits()?;
+        // Only an overflow can fail, the digits being checked already
+        digits
+            .parse()
+            .map_err(|_| self.error_at(column, InstructionErrorKind::NumberOutOfRange(digits)))
+    }
+
+    fn multiplier(&mut self) -> Result<usize, InstructionParsingError> {
+        let (column, digits) = self.digits()?;
+        match digits.parse() {
+            Ok(multiplier @ 1..=MAXIMUM_MULTIPLIER) => Ok(multiplier),
+            _ => Err(self.error_at(column, InstructionErrorKind::InvalidMultiplier(digits))),
+        }
+    }
+
+    fn end(&mut self) -> Result<(), InstructionParsingError> {
+        match self.peek_token() {
+            Some(character) => {
+                Err(self.error(InstructionErrorKind::UnexpectedCharacter(character)))
+            }
+            None => Ok(()),
+        }
+    }
 }
 
-struct RotationInstruction {
-    direction: Direction,
-    distance: usize,
+/// A rotation of the dial, written as `L68` or `R48` in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationInstruction {
+    pub direction: Direction,
+    pub distance: u64,
 }
 
 impl RotationInstruction {
-    fn new(instruction_string: &str) -> Result<Self, InstructionParsingError> {
-        let direction_string = &instruction_string[0..1];
-        let direction = match direction_string {
-            "L" => Ok(Direction::Left),
-            "R" => Ok(Direction::Right),
-            _ => Err(InstructionParsingError::InvalidDirection(
-                direction_string.to_string(),
-            )),
-        }?;
-        let distance: usize = instruction_string[1..].parse()?;
+    /// Parse a single rotation, e.g. `L68`, without the multiplier, the absolute moves and
+    /// the comments of [`parse_instructions`].
+    pub fn new(instruction_string: &str) -> Result<Self, InstructionParsingError> {
+        let mut cursor = LineCursor::new(1, instruction_string, false);
+        let direction = match cursor.next_token() {
+            Some('L') => Direction::Left,
+            Some('R') => Direction::Right,
+            Some(character) => {
+                return Err(cursor.error_at(
+                    cursor.column - 1,
+                    InstructionErrorKind::InvalidDirection(character),
+                ));
+            }
+            None => return Err(cursor.error(InstructionErrorKind::EmptyInstruction)),
+        };
+        let distance = cursor.number()?;
+        cursor.end()?;
         Ok(RotationInstruction {
             direction,
             distance,
@@ -41,56 +208,511 @@ impl RotationInstruction {
     }
 }
 
+/// Move of the dial asked by an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Movement {
+    /// Rotation by a distance, written as `L68` or `R48`
+    Rotation(RotationInstruction),
+    /// Rotation to the right up to a position, written as `=42`, which does not move the
+    /// dial if it is already there
+    Absolute(u64),
+}
+
+/// A line of the input, a move repeated a number of times written as `3xR20`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub repetitions: usize,
+    pub movement: Movement,
+}
+
+impl From<RotationInstruction> for Instruction {
+    fn from(rotation: RotationInstruction) -> Self {
+        Instruction {
+            repetitions: 1,
+            movement: Movement::Rotation(rotation),
+        }
+    }
+}
+
+impl Instruction {
+    /// Parse a line of the input, numbered from 1. The whitespace is ignored, as well as
+    /// the comments starting with `#`, and a line without an instruction gives `None`.
+    pub fn parse_line(
+        line_number: usize,
+        line: &str,
+    ) -> Result<Option<Self>, InstructionParsingError> {
+        let mut cursor = LineCursor::new(line_number, line, true);
+        let Some(first_token) = cursor.peek_token() else {
+            return Ok(None);
+        };
+        let repetitions = if first_token.is_ascii_digit() {
+            let multiplier = cursor.multiplier()?;
+            if cursor.peek_token() != Some('x') {
+                return Err(cursor.error(InstructionErrorKind::MissingMultiplierSign));
+            }
+            cursor.next_token();
+            multiplier
+        } else {
+            1
+        };
+
+        let direction_column = cursor.column;
+        let movement = match cursor.next_token() {
+            Some('L') => Movement::Rotation(RotationInstruction {
+                direction: Direction::Left,
+                distance: cursor.number()?,
+            }),
+            Some('R') => Movement::Rotation(RotationInstruction {
+                direction: Direction::Right,
+                distance: cursor.number()?,
+            }),
+            Some('=') => Movement::Absolute(cursor.number()?),
+            Some(character) => {
+                return Err(cursor.error_at(
+                    direction_column,
+                    InstructionErrorKind::InvalidDirection(character),
+                ));
+            }
+            None => return Err(cursor.error(InstructionErrorKind::MissingMovement)),
+        };
+        cursor.end()?;
+        Ok(Some(Instruction {
+            repetitions,
+            movement,
+        }))
+    }
+}
+
+/// Parse the instructions of every line of the input, skipping the blank lines and the
+/// comments.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, InstructionParsingError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| Instruction::parse_line(index + 1, line).transpose())
+        .collect()
+}
+
+/// Number of positions of the dial of the puzzle, numbered from 0 to 99.
+pub const DEFAULT_DIAL_SIZE: usize = 100;
+/// Position of the dial of the puzzle before the first rotation.
+pub const DEFAULT_STARTING_POSITION: usize = 50;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DialError {
+    #[error("A dial needs at least one position")]
+    EmptyDial,
+    #[error("Invalid starting position {position}, the dial is numbered from 0 to {}", size - 1)]
+    InvalidStartingPosition { position: usize, size: usize },
+    #[error("Invalid target position {position}, the dial is numbered from 0 to {}", size - 1)]
+    InvalidTargetPosition { position: u64, size: usize },
+    #[error(
+        "The absolute move to {0} depends on the starting position, only rotations can be \
+         counted for every starting position"
+    )]
+    AbsoluteMove(u64),
+    #[error("The number of times the dial reached 0 exceeds {}", usize::MAX)]
+    CountOverflow,
+}
+
+/// Dial numbered from 0 to its size minus one, counting how many times it landed or passed
+/// on 0.
 #[derive(Debug)]
-struct Dial {
+pub struct Dial {
+    size: usize,
     position: usize,
     n_times_landed_on_zero: usize,
     n_times_passed_on_zero: usize,
 }
 
 impl Dial {
-    fn new(starting_position: usize) -> Self {
-        Self {
+    /// Dial of the puzzle, numbered from 0 to 99.
+    pub fn new(starting_position: usize) -> Result<Self, DialError> {
+        Self::with_size(DEFAULT_DIAL_SIZE, starting_position)
+    }
+
+    /// Dial numbered from 0 to `size - 1`.
+    pub fn with_size(size: usize, starting_position: usize) -> Result<Self, DialError> {
+        if size == 0 {
+            return Err(DialError::EmptyDial);
+        }
+        if starting_position >= size {
+            return Err(DialError::InvalidStartingPosition {
+                position: starting_position,
+                size,
+            });
+        }
+        Ok(Self {
+            size,
             position: starting_position,
             n_times_landed_on_zero: 0,
             n_times_passed_on_zero: 0,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of rotations that ended on 0.
+    pub fn n_times_landed_on_zero(&self) -> usize {
+        self.n_times_landed_on_zero
+    }
+
+    /// Number of times 0 was reached during the rotations, including when landing on it.
+    pub fn n_times_passed_on_zero(&self) -> usize {
+        self.n_times_passed_on_zero
+    }
+
+    /// Rotations of the instructions from the current position of the dial, the absolute
+    /// moves being rotations to the right and the repeated instructions being repeated
+    /// rotations. A repeated absolute move is a single rotation, as the dial is already on
+    /// its target after the first one.
+    pub fn resolve(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<Vec<RotationInstruction>, DialError> {
+        let size = self.size as u64;
+        let mut position = self.position as u64;
+        let mut rotations = Vec::with_capacity(instructions.len());
+        for instruction in instructions {
+            if let Movement::Absolute(target) = instruction.movement
+                && target >= size
+            {
+                return Err(DialError::InvalidTargetPosition {
+                    position: target,
+                    size: self.size,
+                });
+            }
+            let (rotation, repetitions) = match instruction.movement {
+                Movement::Rotation(rotation) => (rotation, instruction.repetitions),
+                Movement::Absolute(target) => (
+                    RotationInstruction {
+                        direction: Direction::Right,
+                        distance: turn(target, size, Direction::Left, position),
+                    },
+                    1,
+                ),
+            };
+            for _ in 0..repetitions {
+                rotations.push(rotation);
+                position = turn(position, size, rotation.direction, rotation.distance % size);
+            }
         }
+        Ok(rotations)
     }
 
-    fn rotate(&mut self, instruction: RotationInstruction) {
-        // Use a signed integer as the absolute position can go below 0 or above 100
-        let mut absolute_position = self.position as i16;
-        match instruction.direction {
-            Direction::Left => absolute_position -= instruction.distance as i16,
-            Direction::Right => absolute_position += instruction.distance as i16,
+    /// Turn the dial by the distance of the instruction, which can be any number of full
+    /// turns.
+    ///
+    /// The passes on 0 are counted without going through an absolute position, so that no
+    /// distance overflows, and an error is returned if a counter cannot hold the new number
+    /// of passes instead of wrapping around.
+    pub fn rotate(&mut self, instruction: RotationInstruction) -> Result<(), DialError> {
+        let size = self.size as u64;
+        let position = self.position as u64;
+        let distance = instruction.distance;
+        // Clicks until 0 is reached for the first time, a full turn when starting on it
+        let distance_to_first_zero = match instruction.direction {
+            Direction::Left if position == 0 => size,
+            Direction::Left => position,
+            Direction::Right => size - position,
         };
-        // Actually not sure this works that way
-        if absolute_position <= 0 {
-            let mut pass_on_zero_for_the_rotation = (absolute_position.abs() / 100) as usize;
-            // If starting position is different from 0
-            if self.position != 0 {
-                pass_on_zero_for_the_rotation += 1
-            };
-            self.n_times_passed_on_zero += pass_on_zero_for_the_rotation
+        let n_passes = if distance < distance_to_first_zero {
+            0
+        } else {
+            (distance - distance_to_first_zero) / size + 1
         };
-        if absolute_position >= 100 {
-            self.n_times_passed_on_zero += (absolute_position / 100) as usize
+
+        let new_position = turn(position, size, instruction.direction, distance % size);
+
+        // Both counters are checked before updating the dial, which is left untouched on error
+        let n_times_passed_on_zero = usize::try_from(n_passes)
+            .ok()
+            .and_then(|n_passes| self.n_times_passed_on_zero.checked_add(n_passes))
+            .ok_or(DialError::CountOverflow)?;
+        let n_times_landed_on_zero = self
+            .n_times_landed_on_zero
+            .checked_add(usize::from(new_position == 0))
+            .ok_or(DialError::CountOverflow)?;
+        self.n_times_passed_on_zero = n_times_passed_on_zero;
+        self.n_times_landed_on_zero = n_times_landed_on_zero;
+        self.position = new_position as usize;
+        Ok(())
+    }
+}
+
+// Position reached from a position of the dial by less than a full turn, without going past
+// the size in the intermediate values
+fn turn(position: u64, size: u64, direction: Direction, offset: u64) -> u64 {
+    match direction {
+        Direction::Left if offset <= position => position - offset,
+        Direction::Left => position + (size - offset),
+        Direction::Right if offset < size - position => position + offset,
+        Direction::Right => offset - (size - position),
+    }
+}
+
+/// Apply every instruction to the dial of the puzzle, starting at 50.
+pub fn run_dial(instructions: &[RotationInstruction]) -> Result<Dial, DialError> {
+    run_dial_from(Dial::new(DEFAULT_STARTING_POSITION)?, instructions)
+}
+
+/// Apply every instruction to the dial, from its current position.
+pub fn run_dial_from(
+    mut dial: Dial,
+    instructions: &[RotationInstruction],
+) -> Result<Dial, DialError> {
+    for instruction in instructions {
+        dial.rotate(*instruction)?;
+    }
+    Ok(dial)
+}
+
+/// Rule counting the times the dial reached 0, the one of each part of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountingRule {
+    /// Only the rotations ending on 0 are counted
+    Landed,
+    /// Every click reaching 0 is counted
+    Passed,
+}
+
+/// Counts of a dial after every instruction, indexed by the starting position of the dial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountsByStartingPosition {
+    pub n_times_landed_on_zero: Vec<usize>,
+    pub n_times_passed_on_zero: Vec<usize>,
+}
+
+impl CountsByStartingPosition {
+    /// Count the times 0 is reached from every starting position of a dial of the size.
+    ///
+    /// A rotation moves every position by the same offset, so a dial started at `p` is at
+    /// `p` plus the offset of all the rotations so far. The rotation ending on 0 is thus the
+    /// one of a single starting position, and the passes on 0 are the full turns plus one
+    /// for a range of starting positions, accumulated in a difference array. The list is
+    /// only walked once, whatever the size of the dial.
+    ///
+    /// An absolute move, whose distance depends on the starting position, is an error.
+    pub fn new(size: usize, instructions: &[Instruction]) -> Result<Self, DialError> {
+        if size == 0 {
+            return Err(DialError::EmptyDial);
+        }
+        let rotations = instructions
+            .iter()
+            .map(|instruction| match instruction.movement {
+                Movement::Rotation(rotation) => {
+                    Ok(iter::repeat_n(rotation, instruction.repetitions))
+                }
+                Movement::Absolute(target) => Err(DialError::AbsoluteMove(target)),
+            })
+            .collect::<Result<Vec<_>, DialError>>()?;
+        let size_u64 = size as u64;
+        // Starting position whose dial is at 0 when the rotations so far moved it by `shift`
+        let position_on_zero = |shift: u64| (size_u64 - shift) % size_u64;
+        let mut n_times_landed_on_zero = vec![0usize; size];
+        let mut n_full_turns = 0usize;
+        // Number of partial turns reaching 0 starting or ending at a position
+        let mut range_starts = vec![0usize; size];
+        let mut range_ends = vec![0usize; size];
+        let mut add_range = |first: u64, last: u64| {
+            range_starts[first as usize] += 1;
+            range_ends[last as usize] += 1;
         };
 
-        // Update final position
-        self.position = ((100 + (absolute_position % 100)) % 100) as usize;
-        if self.position == 0 {
-            self.n_times_landed_on_zero += 1;
+        let mut shift = 0;
+        for rotation in rotations.into_iter().flatten() {
+            let offset = rotation.distance % size_u64;
+            n_full_turns = usize::try_from(rotation.distance / size_u64)
+                .ok()
+                .and_then(|n_turns| n_full_turns.checked_add(n_turns))
+                .ok_or(DialError::CountOverflow)?;
+            // Positions of the dial before the rotation whose remaining clicks reach 0
+            let reaching_zero = match rotation.direction {
+                _ if offset == 0 => None,
+                Direction::Left => Some((1, offset)),
+                Direction::Right => Some((size_u64 - offset, size_u64 - 1)),
+            };
+            if let Some((first, last)) = reaching_zero {
+                let first = turn(first, size_u64, Direction::Left, shift);
+                let last = turn(last, size_u64, Direction::Left, shift);
+                if first <= last {
+                    add_range(first, last);
+                } else {
+                    add_range(first, size_u64 - 1);
+                    add_range(0, last);
+                }
+            }
+            shift = turn(shift, size_u64, rotation.direction, offset);
+            n_times_landed_on_zero[position_on_zero(shift) as usize] += 1;
+        }
+
+        let mut n_ranges = 0;
+        let n_times_passed_on_zero = range_starts
+            .iter()
+            .zip(&range_ends)
+            .map(|(n_starts, n_ends)| {
+                n_ranges += n_starts;
+                let n_passes = n_full_turns.checked_add(n_ranges);
+                n_ranges -= n_ends;
+                n_passes.ok_or(DialError::CountOverflow)
+            })
+            .collect::<Result<Vec<usize>, DialError>>()?;
+        Ok(Self {
+            n_times_landed_on_zero,
+            n_times_passed_on_zero,
+        })
+    }
+
+    /// Starting positions of the dial reaching 0 exactly `target` times with the rule, in
+    /// increasing order.
+    pub fn starting_positions(&self, rule: CountingRule, target: usize) -> Vec<usize> {
+        let counts = match rule {
+            CountingRule::Landed => &self.n_times_landed_on_zero,
+            CountingRule::Passed => &self.n_times_passed_on_zero,
         };
+        counts.iter().positions(|&count| count == target).collect()
     }
 }
 
-pub fn main() {
-    let mut dial = Dial::new(50);
-    fs::read_to_string("data/day_1.txt")
-        .expect("The input to be correctly read.")
-        .lines()
-        .map(|line| RotationInstruction::new(line).expect("the instruction to be correctly parsed"))
-        .for_each(|instruction| dial.rotate(instruction));
-    dbg!(dial);
+/// Starting positions of a dial of the size reaching 0 exactly `target` times with the rule,
+/// the instructions being rotations only.
+pub fn find_starting_positions(
+    size: usize,
+    instructions: &[Instruction],
+    rule: CountingRule,
+    target: usize,
+) -> Result<Vec<usize>, DialError> {
+    Ok(CountsByStartingPosition::new(size, instructions)?.starting_positions(rule, target))
+}
+
+/// State of the dial around a single rotation, with the counters of both rules so far.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RotationStep {
+    /// Number of the rotation, starting at 1
+    pub step: usize,
+    pub direction: Direction,
+    pub distance: u64,
+    pub position_before: usize,
+    pub position_after: usize,
+    /// Number of times 0 was reached during this rotation, including when landing on it
+    pub n_passes_on_zero: usize,
+    pub landed_on_zero: bool,
+    pub n_times_landed_on_zero: usize,
+    pub n_times_passed_on_zero: usize,
 }
+
+/// File format of an exported trace of the dial.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    /// A header line then a line per rotation
+    #[default]
+    Csv,
+    /// An array with an object per rotation
+    Json,
+}
+
+const TRACE_CSV_HEADER: &str = "step,direction,distance,position_before,position_after,\
+n_passes_on_zero,landed_on_zero,n_times_landed_on_zero,n_times_passed_on_zero";
+
+/// Apply every instruction to the dial, recording its state around each rotation.
+pub fn trace_dial_from(
+    mut dial: Dial,
+    instructions: &[RotationInstruction],
+) -> Result<Vec<RotationStep>, DialError> {
+    instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| {
+            let position_before = dial.position;
+            let n_times_passed_before = dial.n_times_passed_on_zero;
+            let n_times_landed_before = dial.n_times_landed_on_zero;
+            dial.rotate(*instruction)?;
+            Ok(RotationStep {
+                step: index + 1,
+                direction: instruction.direction,
+                distance: instruction.distance,
+                position_before,
+                position_after: dial.position,
+                n_passes_on_zero: dial.n_times_passed_on_zero - n_times_passed_before,
+                landed_on_zero: dial.n_times_landed_on_zero > n_times_landed_before,
+                n_times_landed_on_zero: dial.n_times_landed_on_zero,
+                n_times_passed_on_zero: dial.n_times_passed_on_zero,
+            })
+        })
+        .collect()
+}
+
+/// Write the trace in the format, the CSV lines having the fields in the order of
+/// [`RotationStep`].
+pub fn format_trace(steps: &[RotationStep], format: TraceFormat) -> Result<String> {
+    match format {
+        TraceFormat::Csv => {
+            let mut csv = String::from(TRACE_CSV_HEADER);
+            for step in steps {
+                write!(
+                    csv,
+                    "\n{},{},{},{},{},{},{},{},{}",
+                    step.step,
+                    step.direction.letter(),
+                    step.distance,
+                    step.position_before,
+                    step.position_after,
+                    step.n_passes_on_zero,
+                    step.landed_on_zero,
+                    step.n_times_landed_on_zero,
+                    step.n_times_passed_on_zero
+                )?;
+            }
+            Ok(csv)
+        }
+        TraceFormat::Json => Ok(serde_json::to_string_pretty(steps)?),
+    }
+}
+
+/// Random rotations of up to 999 clicks, the size is the number of rotations.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            format!("{}{}", direction, rng.random_range(1..1000))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Part one counts the rotations landing on 0, part two every time the dial reaches 0.
+pub struct FirstDay;
+
+impl Solution for FirstDay {
+    type Input = Vec<Instruction>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_instructions(input)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        let rotations = Dial::new(DEFAULT_STARTING_POSITION)?.resolve(input)?;
+        Ok(run_dial(&rotations)?.n_times_landed_on_zero)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        let rotations = Dial::new(DEFAULT_STARTING_POSITION)?.resolve(input)?;
+        Ok(run_dial(&rotations)?.n_times_passed_on_zero)
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 1,
+    title: "Secret Entrance",
+    solver: &FirstDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
@@ -1,80 +1,89 @@
-use crate::first_day::{Dial, RotationInstruction};
+use proptest::prelude::*;
+use test_case::test_case;
+
+use crate::first_day::{
+    CountingRule, CountsByStartingPosition, Dial, DialError, Direction, FirstDay, Instruction,
+    InstructionErrorKind, InstructionParsingError, Movement, RotationInstruction, RotationStep,
+    TraceFormat, find_starting_positions, format_trace, parse_instructions, run_dial,
+    run_dial_from, trace_dial_from,
+};
+use crate::solution::Solution;
 
 #[test]
 fn test_dial_going_left_goes_back_to_correct_value() {
-    let mut dial = Dial::new(0);
+    let mut dial = Dial::new(0).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Left,
         distance: 1,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 99);
     assert_eq!(dial.n_times_passed_on_zero, 0);
 }
 
 #[test]
 fn test_dial_going_more_than_one_turn_left_has_correct_number_of_passes_on_zero() {
-    let mut dial = Dial::new(0);
+    let mut dial = Dial::new(0).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Left,
         distance: 501,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 99);
     assert_eq!(dial.n_times_passed_on_zero, 5);
 }
 
 #[test]
 fn test_dial_going_right_goes_back_to_valid_value() {
-    let mut dial = Dial::new(0);
+    let mut dial = Dial::new(0).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Right,
         distance: 110,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 10);
     assert_eq!(dial.n_times_passed_on_zero, 1);
 }
 
 #[test]
 fn test_dial_going_more_than_one_turn_right_has_correct_number_of_passes_on_zero() {
-    let mut dial = Dial::new(0);
+    let mut dial = Dial::new(0).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Right,
         distance: 210,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 10);
     assert_eq!(dial.n_times_passed_on_zero, 2);
 }
 
 #[test]
 fn test_dial_going_small_right_is_ok() {
-    let mut dial = Dial::new(90);
+    let mut dial = Dial::new(90).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Right,
         distance: 9,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 99);
     assert_eq!(dial.n_times_passed_on_zero, 0);
 }
 
 #[test]
 fn test_dial_going_small_left_is_ok() {
-    let mut dial = Dial::new(90);
+    let mut dial = Dial::new(90).expect("The starting position to be on the dial");
     let instruction = RotationInstruction {
         direction: super::Direction::Left,
         distance: 9,
     };
-    dial.rotate(instruction);
+    dial.rotate(instruction).expect("The rotation to be valid");
     assert_eq!(dial.position, 81);
     assert_eq!(dial.n_times_passed_on_zero, 0);
 }
 
 #[test]
 fn test_dial_stopping_on_zero_then_going_left_is_counted_only_one_pass_on_zero() {
-    let mut dial = Dial::new(1);
+    let mut dial = Dial::new(1).expect("The starting position to be on the dial");
     let first_instruction = RotationInstruction {
         direction: super::Direction::Right,
         distance: 99,
@@ -83,10 +92,598 @@ fn test_dial_stopping_on_zero_then_going_left_is_counted_only_one_pass_on_zero()
         direction: super::Direction::Left,
         distance: 2,
     };
-    dial.rotate(first_instruction);
+    dial.rotate(first_instruction)
+        .expect("The rotation to be valid");
     assert_eq!(dial.position, 0);
     assert_eq!(dial.n_times_passed_on_zero, 1);
-    dial.rotate(second_instruction);
+    dial.rotate(second_instruction)
+        .expect("The rotation to be valid");
     assert_eq!(dial.position, 98);
     assert_eq!(dial.n_times_passed_on_zero, 1);
 }
+
+#[test_case(""; "empty instruction")]
+#[test_case("é1"; "multi-byte direction")]
+#[test_case("Lé"; "multi-byte distance")]
+#[test_case("U10"; "unknown direction")]
+#[test_case("L 68"; "whitespace before the distance")]
+#[test_case(" L68"; "leading whitespace")]
+#[test_case("L68 # comment"; "comment")]
+#[test_case("3xR20"; "multiplier")]
+#[test_case("=42"; "absolute move")]
+fn test_invalid_instruction_is_rejected(instruction: &str) {
+    assert!(RotationInstruction::new(instruction).is_err())
+}
+
+#[test]
+fn test_empty_instruction_error() {
+    assert_eq!(
+        RotationInstruction::new("").err().map(|error| error.kind),
+        Some(InstructionErrorKind::EmptyInstruction)
+    )
+}
+
+// Reference dial moved one click at a time, returns the final position with the number of
+// rotations landing on 0 and the number of clicks reaching 0
+fn rotate_click_by_click(
+    size: usize,
+    starting_position: usize,
+    instructions: &[RotationInstruction],
+) -> (usize, usize, usize) {
+    let (mut position, mut n_landed, mut n_passed) = (starting_position, 0, 0);
+    for instruction in instructions {
+        for _ in 0..instruction.distance {
+            position = match instruction.direction {
+                Direction::Left => (position + size - 1) % size,
+                Direction::Right => (position + 1) % size,
+            };
+            if position == 0 {
+                n_passed += 1;
+            }
+        }
+        if position == 0 {
+            n_landed += 1;
+        }
+    }
+    (position, n_landed, n_passed)
+}
+
+fn rotation_instruction() -> impl Strategy<Value = RotationInstruction> {
+    (any::<bool>(), 0..1000u64).prop_map(|(left, distance)| RotationInstruction {
+        direction: if left {
+            Direction::Left
+        } else {
+            Direction::Right
+        },
+        distance,
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_dial_matches_click_by_click_rotation(
+        instructions in prop::collection::vec(rotation_instruction(), 0..50)
+    ) {
+        let dial = run_dial(&instructions).expect("The rotations to be valid");
+        prop_assert_eq!(
+            (dial.position, dial.n_times_landed_on_zero, dial.n_times_passed_on_zero),
+            rotate_click_by_click(100, 50, &instructions)
+        );
+    }
+
+    #[test]
+    fn test_dial_of_any_size_matches_click_by_click_rotation(
+        (size, starting_position) in (1..30usize).prop_flat_map(|size| (Just(size), 0..size)),
+        instructions in prop::collection::vec(rotation_instruction(), 0..50)
+    ) {
+        let dial = Dial::with_size(size, starting_position).expect("The dial to be created");
+        let dial = run_dial_from(dial, &instructions).expect("The rotations to be valid");
+        prop_assert_eq!(
+            (dial.position, dial.n_times_landed_on_zero, dial.n_times_passed_on_zero),
+            rotate_click_by_click(size, starting_position, &instructions)
+        );
+    }
+}
+
+#[test_case(0, 0, DialError::EmptyDial; "empty dial")]
+#[test_case(10, 10, DialError::InvalidStartingPosition { position: 10, size: 10 }; "start past the end")]
+fn test_invalid_dial(size: usize, starting_position: usize, expected: DialError) {
+    assert_eq!(
+        Dial::with_size(size, starting_position).err(),
+        Some(expected)
+    )
+}
+
+#[test]
+fn test_puzzle_dial_rejects_start_past_the_end() {
+    assert_eq!(
+        Dial::new(150).err(),
+        Some(DialError::InvalidStartingPosition {
+            position: 150,
+            size: 100
+        })
+    )
+}
+
+#[test]
+fn test_small_dial_passes_on_zero_at_every_turn() {
+    let mut dial = Dial::with_size(7, 3).expect("The dial to be created");
+    dial.rotate(RotationInstruction {
+        direction: Direction::Right,
+        distance: 18,
+    })
+    .expect("The rotation to be valid");
+    assert_eq!(dial.position(), 0);
+    assert_eq!(dial.n_times_passed_on_zero(), 3);
+    assert_eq!(dial.n_times_landed_on_zero(), 1);
+}
+
+#[test_case(Direction::Right, 40_000, 50, 400; "many turns right")]
+#[test_case(Direction::Left, 40_050, 0, 401; "many turns left landing on zero")]
+#[test_case(Direction::Right, u64::MAX, 65, 184_467_440_737_095_516; "largest distance right")]
+#[test_case(Direction::Left, u64::MAX, 35, 184_467_440_737_095_516; "largest distance left")]
+fn test_rotation_of_large_distance(
+    direction: Direction,
+    distance: u64,
+    expected_position: usize,
+    expected_passes: usize,
+) {
+    let mut dial = Dial::new(50).expect("The starting position to be on the dial");
+    dial.rotate(RotationInstruction {
+        direction,
+        distance,
+    })
+    .expect("The rotation to be valid");
+    assert_eq!(dial.position(), expected_position);
+    assert_eq!(dial.n_times_passed_on_zero(), expected_passes);
+}
+
+#[test_case("L 68", 2, InstructionErrorKind::MissingNumber; "whitespace before the distance")]
+#[test_case("L68 # x", 4, InstructionErrorKind::UnexpectedCharacter(' '); "trailing comment")]
+fn test_rotation_does_not_accept_the_extended_grammar(
+    instruction: &str,
+    column: usize,
+    kind: InstructionErrorKind,
+) {
+    assert_eq!(
+        RotationInstruction::new(instruction).err(),
+        Some(InstructionParsingError {
+            line_number: 1,
+            column,
+            line: instruction.to_string(),
+            kind,
+        })
+    );
+}
+
+#[test]
+fn test_largest_distance_is_parsed() {
+    assert_eq!(
+        RotationInstruction::new("R18446744073709551615").expect("The instruction to be valid"),
+        RotationInstruction {
+            direction: Direction::Right,
+            distance: u64::MAX,
+        }
+    );
+}
+
+#[test]
+fn test_distance_out_of_range_is_rejected() {
+    assert_eq!(
+        RotationInstruction::new("L18446744073709551616").err(),
+        Some(InstructionParsingError {
+            line_number: 1,
+            column: 2,
+            line: "L18446744073709551616".to_string(),
+            kind: InstructionErrorKind::NumberOutOfRange("18446744073709551616".to_string()),
+        })
+    )
+}
+
+#[test]
+fn test_overflow_of_the_passes_on_zero_is_an_error() {
+    let instruction = RotationInstruction {
+        direction: Direction::Right,
+        distance: u64::MAX,
+    };
+    let mut dial = Dial::with_size(1, 0).expect("The dial to be created");
+    dial.rotate(instruction)
+        .expect("The first rotation to be valid");
+    assert_eq!(dial.rotate(instruction), Err(DialError::CountOverflow));
+    // The dial is left untouched by the failed rotation
+    assert_eq!(dial.n_times_passed_on_zero(), usize::MAX);
+    assert_eq!(dial.n_times_landed_on_zero(), 1);
+}
+
+fn example_instructions() -> Vec<RotationInstruction> {
+    [
+        "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
+    ]
+    .into_iter()
+    .map(|instruction| RotationInstruction::new(instruction).expect("The instruction to be valid"))
+    .collect()
+}
+
+fn example_input() -> Vec<Instruction> {
+    example_instructions()
+        .into_iter()
+        .map(Instruction::from)
+        .collect()
+}
+
+#[test]
+fn test_trace_of_the_example() {
+    let trace = trace_dial_from(
+        Dial::new(50).expect("The starting position to be on the dial"),
+        &example_instructions(),
+    )
+    .expect("The rotations to be valid");
+    assert_eq!(trace.len(), 10);
+    assert_eq!(
+        trace[0],
+        RotationStep {
+            step: 1,
+            direction: Direction::Left,
+            distance: 68,
+            position_before: 50,
+            position_after: 82,
+            n_passes_on_zero: 1,
+            landed_on_zero: false,
+            n_times_landed_on_zero: 0,
+            n_times_passed_on_zero: 1,
+        }
+    );
+    assert_eq!(
+        trace[2],
+        RotationStep {
+            step: 3,
+            direction: Direction::Right,
+            distance: 48,
+            position_before: 52,
+            position_after: 0,
+            n_passes_on_zero: 1,
+            landed_on_zero: true,
+            n_times_landed_on_zero: 1,
+            n_times_passed_on_zero: 2,
+        }
+    );
+    let last = trace.last().expect("The trace to have steps");
+    assert_eq!(
+        (last.n_times_landed_on_zero, last.n_times_passed_on_zero),
+        (3, 6)
+    );
+}
+
+#[test]
+fn test_trace_exported_as_csv() {
+    let trace = trace_dial_from(
+        Dial::new(50).expect("The starting position to be on the dial"),
+        &example_instructions()[..3],
+    )
+    .expect("The rotations to be valid");
+    assert_eq!(
+        format_trace(&trace, TraceFormat::Csv).expect("The trace to be formatted"),
+        "step,direction,distance,position_before,position_after,n_passes_on_zero,landed_on_zero,\
+         n_times_landed_on_zero,n_times_passed_on_zero\n\
+         1,L,68,50,82,1,false,0,1\n\
+         2,L,30,82,52,0,false,0,1\n\
+         3,R,48,52,0,1,true,1,2"
+    );
+}
+
+#[test]
+fn test_trace_exported_as_json() {
+    let trace = trace_dial_from(
+        Dial::new(50).expect("The starting position to be on the dial"),
+        &example_instructions()[..1],
+    )
+    .expect("The rotations to be valid");
+    let json: serde_json::Value = serde_json::from_str(
+        &format_trace(&trace, TraceFormat::Json).expect("The trace to be formatted"),
+    )
+    .expect("The trace to be valid JSON");
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "step": 1,
+            "direction": "L",
+            "distance": 68,
+            "position_before": 50,
+            "position_after": 82,
+            "n_passes_on_zero": 1,
+            "landed_on_zero": false,
+            "n_times_landed_on_zero": 0,
+            "n_times_passed_on_zero": 1,
+        }])
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_trace_ends_with_the_counts_of_the_dial(
+        instructions in prop::collection::vec(rotation_instruction(), 1..50)
+    ) {
+        let trace = trace_dial_from(Dial::new(50).expect("The starting position to be on the dial"), &instructions)
+            .expect("The rotations to be valid");
+        let dial = run_dial(&instructions).expect("The rotations to be valid");
+        let last = trace.last().expect("The trace to have steps");
+        prop_assert_eq!(last.position_after, dial.position);
+        prop_assert_eq!(last.n_times_landed_on_zero, dial.n_times_landed_on_zero);
+        prop_assert_eq!(
+            trace.iter().map(|step| step.n_passes_on_zero).sum::<usize>(),
+            dial.n_times_passed_on_zero
+        );
+    }
+}
+
+#[test_case(CountingRule::Landed, 3; "landed like the example")]
+#[test_case(CountingRule::Passed, 6; "passed like the example")]
+fn test_example_starting_position_is_found(rule: CountingRule, target: usize) {
+    let starting_positions = find_starting_positions(100, &example_input(), rule, target)
+        .expect("The rotations to be valid");
+    assert!(starting_positions.contains(&50));
+}
+
+#[test]
+fn test_starting_positions_of_a_small_dial() {
+    let instructions = [
+        RotationInstruction {
+            direction: Direction::Right,
+            distance: 3,
+        },
+        RotationInstruction {
+            direction: Direction::Left,
+            distance: 9,
+        },
+    ];
+    let instructions = instructions.map(Instruction::from);
+    let counts = CountsByStartingPosition::new(5, &instructions).expect("The counts to be valid");
+    // From 0: R3 to 3, then L9 to 4 passing 0 twice
+    // From 1: R3 to 4, then L9 to 0 passing 0 twice
+    // From 2: R3 to 0, then L9 to 1 passing 0 once
+    // From 3: R3 to 1 passing 0, then L9 to 2 passing 0 twice
+    // From 4: R3 to 2 passing 0, then L9 to 3 passing 0 twice
+    assert_eq!(counts.n_times_landed_on_zero, vec![0, 1, 1, 0, 0]);
+    assert_eq!(counts.n_times_passed_on_zero, vec![2, 2, 2, 3, 3]);
+    assert_eq!(
+        counts.starting_positions(CountingRule::Landed, 1),
+        vec![1, 2]
+    );
+    assert_eq!(
+        counts.starting_positions(CountingRule::Passed, 3),
+        vec![3, 4]
+    );
+    assert!(
+        counts
+            .starting_positions(CountingRule::Passed, 4)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_starting_positions_with_repeated_rotations() {
+    let repeated = parse_instructions("3xR20\nL7").expect("The instructions to be valid");
+    let expanded = parse_instructions("R20\nR20\nR20\nL7").expect("The instructions to be valid");
+    assert_eq!(
+        CountsByStartingPosition::new(30, &repeated),
+        CountsByStartingPosition::new(30, &expanded)
+    );
+}
+
+#[test]
+fn test_starting_positions_with_absolute_move_is_an_error() {
+    let instructions = parse_instructions("R20\n=42").expect("The instructions to be valid");
+    assert_eq!(
+        find_starting_positions(100, &instructions, CountingRule::Landed, 1),
+        Err(DialError::AbsoluteMove(42))
+    );
+}
+
+#[test]
+fn test_starting_positions_of_an_empty_dial() {
+    assert_eq!(
+        CountsByStartingPosition::new(0, &[]),
+        Err(DialError::EmptyDial)
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_counts_by_starting_position_match_a_dial_per_position(
+        size in 1..30usize,
+        instructions in prop::collection::vec(rotation_instruction(), 0..50)
+    ) {
+        let rotations = instructions
+            .iter()
+            .map(|&rotation| Instruction::from(rotation))
+            .collect::<Vec<Instruction>>();
+        let counts = CountsByStartingPosition::new(size, &rotations)
+            .expect("The counts to be valid");
+        for starting_position in 0..size {
+            let dial = Dial::with_size(size, starting_position).expect("The dial to be created");
+            let dial = run_dial_from(dial, &instructions).expect("The rotations to be valid");
+            prop_assert_eq!(
+                counts.n_times_landed_on_zero[starting_position],
+                dial.n_times_landed_on_zero
+            );
+            prop_assert_eq!(
+                counts.n_times_passed_on_zero[starting_position],
+                dial.n_times_passed_on_zero
+            );
+        }
+    }
+}
+
+fn rotation(direction: Direction, distance: u64) -> Movement {
+    Movement::Rotation(RotationInstruction {
+        direction,
+        distance,
+    })
+}
+
+#[test_case("L68", 1, rotation(Direction::Left, 68); "plain rotation")]
+#[test_case("  R 48  ", 1, rotation(Direction::Right, 48); "whitespace")]
+#[test_case("R48 # back to zero", 1, rotation(Direction::Right, 48); "trailing comment")]
+#[test_case("3xR20", 3, rotation(Direction::Right, 20); "multiplier")]
+#[test_case("12 x L5", 12, rotation(Direction::Left, 5); "spaced multiplier")]
+#[test_case("=42", 1, Movement::Absolute(42); "absolute move")]
+#[test_case("2x= 0", 2, Movement::Absolute(0); "repeated absolute move")]
+fn test_instruction_is_parsed(line: &str, repetitions: usize, movement: Movement) {
+    assert_eq!(
+        Instruction::parse_line(1, line).expect("The line to be valid"),
+        Some(Instruction {
+            repetitions,
+            movement,
+        })
+    );
+}
+
+#[test_case(""; "empty line")]
+#[test_case("   \t"; "whitespace")]
+#[test_case("# L68"; "comment")]
+#[test_case("  # L68"; "indented comment")]
+fn test_line_without_instruction_is_skipped(line: &str) {
+    assert_eq!(
+        Instruction::parse_line(1, line).expect("The line to be valid"),
+        None
+    );
+}
+
+#[test_case("U10", 1, InstructionErrorKind::InvalidDirection('U'); "unknown direction")]
+#[test_case("  é1", 3, InstructionErrorKind::InvalidDirection('é'); "multi-byte direction")]
+#[test_case("Lé", 2, InstructionErrorKind::MissingNumber; "multi-byte distance")]
+#[test_case("R", 2, InstructionErrorKind::MissingNumber; "missing distance")]
+#[test_case("= # comment", 3, InstructionErrorKind::MissingNumber; "missing target")]
+#[test_case("3R20", 2, InstructionErrorKind::MissingMultiplierSign; "missing multiplier sign")]
+#[test_case("3", 2, InstructionErrorKind::MissingMultiplierSign; "multiplier alone")]
+#[test_case("3 # R20", 3, InstructionErrorKind::MissingMultiplierSign; "multiplier before a comment")]
+#[test_case("3x", 3, InstructionErrorKind::MissingMovement; "missing movement")]
+#[test_case("0xR20", 1, InstructionErrorKind::InvalidMultiplier("0".to_string()); "zero multiplier")]
+#[test_case("99999xR20", 1, InstructionErrorKind::InvalidMultiplier("99999".to_string()); "multiplier too large")]
+#[test_case("R1 R2", 4, InstructionErrorKind::UnexpectedCharacter('R'); "two instructions")]
+#[test_case("R1x", 3, InstructionErrorKind::UnexpectedCharacter('x'); "trailing character")]
+#[test_case("=18446744073709551616", 2, InstructionErrorKind::NumberOutOfRange("18446744073709551616".to_string()); "target out of range")]
+fn test_invalid_line_reports_its_column(line: &str, column: usize, kind: InstructionErrorKind) {
+    assert_eq!(
+        Instruction::parse_line(7, line),
+        Err(InstructionParsingError {
+            line_number: 7,
+            column,
+            line: line.to_string(),
+            kind,
+        })
+    );
+}
+
+#[test]
+fn test_instructions_error_reports_the_line() {
+    let error = parse_instructions("# Rotations\nL68\n\nR4 8\n")
+        .expect_err("The fourth line to be invalid");
+    assert_eq!((error.line_number, error.column), (4, 4));
+    assert_eq!(
+        error.to_string(),
+        "Invalid line 4: \"R4 8\": Unexpected '8' after the instruction at column 4"
+    );
+}
+
+#[test]
+fn test_instructions_are_resolved_into_rotations() {
+    let instructions = parse_instructions("# Rotations\n2xR30\n\n=10 # already there\n=10\nL5\n")
+        .expect("The instructions to be valid");
+    let rotations = Dial::new(50)
+        .expect("The starting position to be on the dial")
+        .resolve(&instructions)
+        .expect("The targets to be on the dial");
+    let right = |distance| RotationInstruction {
+        direction: Direction::Right,
+        distance,
+    };
+    assert_eq!(
+        rotations,
+        vec![
+            right(30),
+            right(30),
+            right(0),
+            right(0),
+            RotationInstruction {
+                direction: Direction::Left,
+                distance: 5,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_absolute_move_turns_right_across_zero() {
+    let instructions = parse_instructions("R30\n=10").expect("The instructions to be valid");
+    let rotations = Dial::new(50)
+        .expect("The starting position to be on the dial")
+        .resolve(&instructions)
+        .expect("The target to be on the dial");
+    assert_eq!(rotations[1].distance, 30);
+    let dial = run_dial(&rotations).expect("The rotations to be valid");
+    assert_eq!(dial.position(), 10);
+    assert_eq!(dial.n_times_passed_on_zero(), 1);
+}
+
+#[test]
+fn test_repeated_absolute_move_only_moves_once() {
+    let instructions = parse_instructions("2x=60").expect("The instructions to be valid");
+    let rotations = Dial::new(50)
+        .expect("The starting position to be on the dial")
+        .resolve(&instructions)
+        .expect("The target to be on the dial");
+    assert_eq!(
+        rotations,
+        vec![RotationInstruction {
+            direction: Direction::Right,
+            distance: 10,
+        }]
+    );
+    let dial = run_dial(&rotations).expect("The rotations to be valid");
+    assert_eq!(dial.position(), 60);
+}
+
+#[test]
+fn test_repeated_absolute_move_lands_once() {
+    let instructions = parse_instructions("3x=0").expect("The instructions to be valid");
+    let dial = Dial::new(0).expect("The starting position to be on the dial");
+    let rotations = dial
+        .resolve(&instructions)
+        .expect("The target to be on the dial");
+    assert_eq!(rotations.len(), 1);
+    let dial = run_dial_from(dial, &rotations).expect("The rotations to be valid");
+    assert_eq!(dial.n_times_landed_on_zero(), 1);
+}
+
+#[test]
+fn test_absolute_move_off_the_dial_is_rejected() {
+    let instructions = parse_instructions("=100").expect("The instructions to be valid");
+    assert_eq!(
+        Dial::new(50)
+            .expect("The starting position to be on the dial")
+            .resolve(&instructions),
+        Err(DialError::InvalidTargetPosition {
+            position: 100,
+            size: 100,
+        })
+    );
+}
+
+#[test]
+fn test_annotated_example_gives_the_same_answers() {
+    let annotated = "# The example, with a comment per group of rotations\n\
+                     L68\n  L30\nR48   # lands on 0\n\n\
+                     L5\nR60\nL55 # lands on 0\n\
+                     L1\nL99 # lands on 0\n\
+                     R14\nL82\n";
+    let input = FirstDay::parse(annotated).expect("The input to be valid");
+    assert_eq!(
+        FirstDay::part_one(&input).expect("The first part to be solved"),
+        3
+    );
+    assert_eq!(
+        FirstDay::part_two(&input).expect("The second part to be solved"),
+        6
+    );
+}
@@ -1,16 +1,28 @@
+//! Day 4: Printing Department, finding the rolls of paper a forklift can access.
+
 #[cfg(test)]
 mod tests;
 
-use std::fs;
+use anyhow::Result;
+use rand::RngExt;
+
+use crate::{
+    generator::InputRng,
+    registry::Day,
+    solution::Solution,
+    visualization::{Color, Picture, Shape},
+};
 
+/// A roll of paper of the grid, x being its row and y its column.
 #[derive(Debug, Ord, Eq, PartialEq, PartialOrd, Clone)]
-struct RollOfPaper {
-    x: usize,
-    y: usize,
+pub struct RollOfPaper {
+    pub x: usize,
+    pub y: usize,
 }
 
 impl RollOfPaper {
-    fn is_adjacent(&self, other: &Self) -> bool {
+    /// Whether the other roll is one of the 8 neighbours of this roll.
+    pub fn is_adjacent(&self, other: &Self) -> bool {
         let mut conditions = (self != other) & (self.x <= other.x + 1) & (self.y <= other.y + 1);
         if other.x > 0 {
             conditions = conditions & (self.x >= other.x - 1)
@@ -22,7 +34,8 @@ impl RollOfPaper {
     }
 }
 
-fn brute_force_accessible_rolls(
+/// Rolls having at most `maximum_adjacent_rolls` neighbours.
+pub fn brute_force_accessible_rolls(
     rolls: &Vec<RollOfPaper>,
     maximum_adjacent_rolls: usize,
 ) -> Vec<RollOfPaper> {
@@ -52,7 +65,8 @@ fn remove_available_rolls(rolls: &mut Vec<RollOfPaper>, available_rolls: &Vec<Ro
         .for_each(drop);
 }
 
-fn count_accessible_rolls_with_iterative_removal(mut rolls: Vec<RollOfPaper>) -> usize {
+/// Number of rolls removed when repeatedly removing the accessible rolls.
+pub fn count_accessible_rolls_with_iterative_removal(mut rolls: Vec<RollOfPaper>) -> usize {
     let mut total_removed_rolls = 0;
     let mut available_rolls = brute_force_accessible_rolls(&rolls, 3);
     while !available_rolls.is_empty() {
@@ -63,12 +77,12 @@ fn count_accessible_rolls_with_iterative_removal(mut rolls: Vec<RollOfPaper>) ->
     total_removed_rolls
 }
 
-pub fn main() {
-    let mut rolls = fs::read_to_string("data/day_4.txt")
-        .expect("The input to be correctly read")
+/// Parse the grid where the rolls are marked with `@`, the rolls are sorted.
+pub fn parse_rolls(input: &str) -> Vec<RollOfPaper> {
+    let mut rolls = input
         .lines()
         .enumerate()
-        .map(|(row_index, row)| {
+        .flat_map(|(row_index, row)| {
             row.chars()
                 .enumerate()
                 .filter_map(move |(col_index, element)| {
@@ -77,12 +91,92 @@ pub fn main() {
                         y: col_index,
                     })
                 })
-                .collect::<Vec<RollOfPaper>>()
         })
-        .flatten()
         .collect::<Vec<RollOfPaper>>();
     rolls.sort();
-    let available_rolls = brute_force_accessible_rolls(&rolls, 3);
-    dbg!(available_rolls.len());
-    dbg!(count_accessible_rolls_with_iterative_removal(rolls));
+    rolls
+}
+
+/// Random square grid with a roll on 60% of the positions, the size is the side of the grid.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.random_bool(0.6) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// One frame per round of removal, the rolls accessible in the round are drawn in red and
+/// the rolls removed in the previous rounds in gray.
+pub fn visualize(input: &str) -> Result<Vec<Picture>> {
+    let mut rolls = parse_rolls(input);
+    let width = rolls.iter().map(|roll| roll.y + 1).max().unwrap_or(0);
+    let height = rolls.iter().map(|roll| roll.x + 1).max().unwrap_or(0);
+    let cell = |roll: &RollOfPaper, glyph: char, color: Color| Shape::Cell {
+        at: (roll.y, roll.x),
+        glyph,
+        color,
+    };
+    let mut removed_rolls = Vec::new();
+    let mut frames = Vec::new();
+    loop {
+        let available_rolls = brute_force_accessible_rolls(&rolls, 3);
+        let mut picture = Picture::new(
+            format!(
+                "Round {}: {} accessible rolls, {} removed",
+                frames.len() + 1,
+                available_rolls.len(),
+                removed_rolls.len()
+            ),
+            width,
+            height,
+        );
+        for roll in &removed_rolls {
+            picture.push(cell(roll, '.', Color::Gray));
+        }
+        for roll in &rolls {
+            picture.push(cell(roll, '@', Color::Green));
+        }
+        for roll in &available_rolls {
+            picture.push(cell(roll, '@', Color::Red));
+        }
+        frames.push(picture);
+        if available_rolls.is_empty() {
+            return Ok(frames);
+        }
+        remove_available_rolls(&mut rolls, &available_rolls);
+        removed_rolls.extend(available_rolls);
+    }
+}
+
+/// Part one counts the accessible rolls, part two the rolls removed iteratively.
+pub struct FourthDay;
+
+impl Solution for FourthDay {
+    type Input = Vec<RollOfPaper>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_rolls(input))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(brute_force_accessible_rolls(input, 3).len())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        Ok(count_accessible_rolls_with_iterative_removal(input.clone()))
+    }
 }
+
+pub const DAY: Day = Day {
+    number: 4,
+    title: "Printing Department",
+    solver: &FourthDay,
+    generator: Some(generate_input),
+    visualizer: Some(visualize),
+};
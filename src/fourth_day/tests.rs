@@ -1,4 +1,13 @@
-use crate::fourth_day::RollOfPaper;
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+use crate::{
+    fourth_day::{
+        RollOfPaper, brute_force_accessible_rolls, count_accessible_rolls_with_iterative_removal,
+        generate_input, parse_rolls,
+    },
+    generator::InputRng,
+};
 
 #[test]
 fn test_same_roll_is_not_adjacent() {
@@ -75,3 +84,67 @@ fn test_non_adjacent_roll_close_column() {
     let other_roll = RollOfPaper { x: 4, y: 2 };
     assert!(!roll.is_adjacent(&other_roll))
 }
+
+// Reference rolls accessible on the grid, counting the rolls of the 8 neighbouring cells
+fn accessible_rolls_of_grid(grid: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let mut accessible_rolls = Vec::new();
+    for (x, row) in grid.iter().enumerate() {
+        for (y, &is_roll) in row.iter().enumerate() {
+            let n_adjacent_rolls = (x.saturating_sub(1)..=x + 1)
+                .flat_map(|neighbour_x| {
+                    (y.saturating_sub(1)..=y + 1).map(move |neighbour_y| (neighbour_x, neighbour_y))
+                })
+                .filter(|&neighbour| neighbour != (x, y))
+                .filter(|&(neighbour_x, neighbour_y)| {
+                    grid.get(neighbour_x)
+                        .and_then(|row| row.get(neighbour_y))
+                        .is_some_and(|&is_roll| is_roll)
+                })
+                .count();
+            if is_roll && n_adjacent_rolls < 4 {
+                accessible_rolls.push((x, y));
+            }
+        }
+    }
+    accessible_rolls
+}
+
+// Reference number of rolls removed, removing the accessible rolls until none is left
+fn count_removed_rolls_of_grid(mut grid: Vec<Vec<bool>>) -> usize {
+    let mut n_removed_rolls = 0;
+    loop {
+        let accessible_rolls = accessible_rolls_of_grid(&grid);
+        if accessible_rolls.is_empty() {
+            return n_removed_rolls;
+        }
+        n_removed_rolls += accessible_rolls.len();
+        for (x, y) in accessible_rolls {
+            grid[x][y] = false;
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_accessible_rolls_match_grid_neighbours(seed in any::<u64>(), size in 1..15usize) {
+        let input = generate_input(&mut InputRng::seed_from_u64(seed), size);
+        let grid = input
+            .lines()
+            .map(|row| row.chars().map(|element| element == '@').collect())
+            .collect::<Vec<Vec<bool>>>();
+        let rolls = parse_rolls(&input);
+        prop_assert_eq!(
+            brute_force_accessible_rolls(&rolls, 3)
+                .into_iter()
+                .map(|roll| (roll.x, roll.y))
+                .collect::<Vec<(usize, usize)>>(),
+            accessible_rolls_of_grid(&grid)
+        );
+        prop_assert_eq!(
+            count_accessible_rolls_with_iterative_removal(rolls),
+            count_removed_rolls_of_grid(grid)
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::{Result, anyhow};
+use rand::{SeedableRng, rngs::Xoshiro256PlusPlus};
+
+use crate::registry::Day;
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Random number generator of the input generators, its sequence only depends on the seed
+/// so a generated input can be reproduced.
+pub type InputRng = Xoshiro256PlusPlus;
+
+/// Random input of a day, the size scales the input: the number of lines, of items or the
+/// side of a grid depending on the day.
+pub type InputGenerator = fn(&mut InputRng, usize) -> String;
+
+/// Generate a random input of the day, the same seed and size always give the same input.
+pub fn generate(day: &Day, seed: u64, size: usize) -> Result<String> {
+    let generator = day
+        .generator
+        .ok_or_else(|| anyhow!("Day {} does not have an input generator", day.number))?;
+    Ok(generator(&mut InputRng::seed_from_u64(seed), size))
+}
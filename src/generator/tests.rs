@@ -0,0 +1,34 @@
+use crate::{
+    generator::generate,
+    registry::{DAYS, get_day},
+    solution::Part,
+};
+
+#[test]
+fn test_same_seed_generates_the_same_input() {
+    let day = get_day(1).expect("The day to be registered");
+    let input = generate(day, 42, 20).expect("The input to be generated");
+    assert_eq!(input.lines().count(), 20);
+    assert_eq!(
+        generate(day, 42, 20).expect("The input to be generated"),
+        input
+    );
+    assert_ne!(
+        generate(day, 43, 20).expect("The input to be generated"),
+        input
+    );
+}
+
+#[test]
+fn test_generated_inputs_are_solved() {
+    for day in DAYS {
+        let input = generate(day, 7, 50).expect("The input to be generated");
+        let answers = day.run(&input, Part::Both);
+        assert!(
+            answers.is_ok(),
+            "Day {} could not solve its generated input: {:?}",
+            day.number,
+            answers
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{Read, stdin},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::{fetcher::InputFetcher, registry::Day};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_INPUT_DIRECTORY: &str = "data";
+// Value of the input path to read the input from stdin
+const STDIN_INPUT: &str = "-";
+
+/// Directory of the inputs named day_<number>.txt. When a fetcher is configured, the missing
+/// inputs are downloaded and stored in the directory, so they are only downloaded once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCache {
+    pub directory: PathBuf,
+    pub fetcher: Option<InputFetcher>,
+}
+
+impl Default for InputCache {
+    fn default() -> Self {
+        InputCache::new(None, None)
+    }
+}
+
+impl InputCache {
+    pub fn new(directory: Option<&Path>, fetcher: Option<InputFetcher>) -> Self {
+        InputCache {
+            directory: directory
+                .unwrap_or(Path::new(DEFAULT_INPUT_DIRECTORY))
+                .to_path_buf(),
+            fetcher,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.directory.join(format!("day_{}.txt", day))
+    }
+
+    pub fn get(&self, day: u8) -> Result<String> {
+        let path = self.path(day);
+        match &self.fetcher {
+            Some(fetcher) if !path.exists() => {
+                let input = fetcher.fetch(day)?;
+                fs::create_dir_all(&self.directory).with_context(|| {
+                    format!(
+                        "Could not create the directory {}",
+                        self.directory.display()
+                    )
+                })?;
+                fs::write(&path, &input)
+                    .with_context(|| format!("Could not cache the input in {}", path.display()))?;
+                Ok(input)
+            }
+            _ => fs::read_to_string(&path)
+                .with_context(|| format!("Could not read the input file {}", path.display())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Cache { day: u8, cache: InputCache },
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Cache { day, cache } => write!(f, "{}", cache.path(*day).display()),
+        }
+    }
+}
+
+impl InputSource {
+    /// An explicit input takes precedence over the input cache,
+    /// which defaults to data/ where the inputs are named day_<number>.txt
+    pub fn resolve(day: &Day, input: Option<&str>, cache: &InputCache) -> Self {
+        match input {
+            Some(STDIN_INPUT) => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::Cache {
+                day: day.number,
+                cache: cache.clone(),
+            },
+        }
+    }
+
+    /// Path of the file the input is read from, stdin has none.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Cache { day, cache } => Some(cache.path(*day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("Could not read the input file {}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                stdin()
+                    .read_to_string(&mut input)
+                    .context("Could not read the input from stdin")?;
+                Ok(input)
+            }
+            InputSource::Cache { day, cache } => cache.get(*day),
+        }
+    }
+}
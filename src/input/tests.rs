@@ -0,0 +1,112 @@
+use std::{
+    env::temp_dir,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    fetcher::InputFetcher,
+    input::{InputCache, InputSource},
+    registry::get_day,
+    test_server::TestServer,
+};
+
+#[test]
+fn test_default_input_path() {
+    let day = get_day(7).expect("The day to be registered");
+    let input_source = InputSource::resolve(day, None, &InputCache::default());
+    assert_eq!(input_source.to_string(), "data/day_7.txt")
+}
+
+#[test]
+fn test_input_directory_override() {
+    let day = get_day(7).expect("The day to be registered");
+    let input_source = InputSource::resolve(
+        day,
+        None,
+        &InputCache::new(Some(Path::new("examples")), None),
+    );
+    assert_eq!(input_source.to_string(), "examples/day_7.txt")
+}
+
+#[test]
+fn test_explicit_input_takes_precedence() {
+    let day = get_day(7).expect("The day to be registered");
+    assert_eq!(
+        InputSource::resolve(
+            day,
+            Some("stress.txt"),
+            &InputCache::new(Some(Path::new("examples")), None)
+        ),
+        InputSource::File(PathBuf::from("stress.txt"))
+    )
+}
+
+#[test]
+fn test_stdin_input() {
+    let day = get_day(7).expect("The day to be registered");
+    assert_eq!(
+        InputSource::resolve(day, Some("-"), &InputCache::default()),
+        InputSource::Stdin
+    )
+}
+
+#[test]
+fn test_read_file_input() {
+    let path = temp_dir().join("advent_of_code_2025_input_test.txt");
+    fs::write(&path, "L68\nL30").expect("The temporary file to be written");
+    let input = InputSource::File(path.clone()).read();
+    fs::remove_file(&path).expect("The temporary file to be removed");
+    assert_eq!(input.expect("The input to be read"), "L68\nL30")
+}
+
+#[test]
+fn test_read_missing_file_fails() {
+    let input = InputSource::File(PathBuf::from("data/missing_day.txt")).read();
+    assert!(input.is_err())
+}
+
+#[test]
+fn test_missing_input_without_fetcher_fails() {
+    let cache = InputCache::new(Some(Path::new("data/missing_directory")), None);
+    assert!(cache.get(1).is_err())
+}
+
+#[test]
+fn test_missing_input_is_downloaded_once() {
+    let directory = temp_dir().join("advent_of_code_2025_input_cache_test");
+    let _ = fs::remove_dir_all(&directory);
+    let server = TestServer::start(200, "L68\nL30\n");
+    let cache = InputCache::new(
+        Some(&directory),
+        Some(InputFetcher::new(&server.url, "secret")),
+    );
+
+    let first_input = cache.get(1).expect("The input to be downloaded");
+    let second_input = cache.get(1).expect("The input to be read from the cache");
+    let cached_input = fs::read_to_string(directory.join("day_1.txt"));
+    fs::remove_dir_all(&directory).expect("The cache directory to be removed");
+
+    assert_eq!(first_input, "L68\nL30\n");
+    assert_eq!(second_input, first_input);
+    assert_eq!(cached_input.expect("The input to be cached"), first_input);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_cached_input_is_not_downloaded() {
+    let directory = temp_dir().join("advent_of_code_2025_input_cache_hit_test");
+    fs::create_dir_all(&directory).expect("The cache directory to be created");
+    fs::write(directory.join("day_2.txt"), "11-22").expect("The input to be cached");
+    let server = TestServer::start(500, "");
+    let cache = InputCache::new(
+        Some(&directory),
+        Some(InputFetcher::new(&server.url, "secret")),
+    );
+
+    let input = cache.get(2);
+    fs::remove_dir_all(&directory).expect("The cache directory to be removed");
+
+    assert_eq!(input.expect("The input to be read from the cache"), "11-22");
+    assert!(server.requests().is_empty());
+}
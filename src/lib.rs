@@ -0,0 +1,50 @@
+//! Solutions of the Advent of Code 2025 puzzles.
+//!
+//! Each day has its own module exposing the parser of its input, its solvers and a `DAY`
+//! constant registering it in [`registry::DAYS`]. The days implement the [`solution::Solution`]
+//! trait, so they can be run uniformly through [`registry::Day::run`]:
+//!
+//! ```
+//! use advent_of_code_2025::{
+//!     registry::get_day,
+//!     solution::{Answer, Part},
+//! };
+//!
+//! let day = get_day(1).expect("The first day to be registered");
+//! let answers = day.run("L68\nR48", Part::Both)?;
+//! assert_eq!(answers.first, Some(Answer::Number(0)));
+//! assert_eq!(answers.second, Some(Answer::Number(2)));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! The other modules are the building blocks of the runner: reading the inputs, reporting the
+//! results, benchmarking the days and verifying their answers.
+
+pub mod benchmark;
+pub mod budget;
+pub mod eight_day;
+pub mod eleventh_day;
+pub mod examples;
+pub mod fetcher;
+pub mod fifth_day;
+pub mod first_day;
+pub mod fourth_day;
+pub mod generator;
+pub mod input;
+pub mod ninth_day;
+pub mod parsing;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod second_day;
+pub mod seventh_day;
+pub mod sixth_day;
+pub mod solution;
+pub mod submission;
+pub mod tenth_day;
+#[cfg(test)]
+mod test_server;
+pub mod third_day;
+pub mod verification;
+pub mod visualization;
+pub mod watch;
@@ -1,54 +1,517 @@
-use enum_display::EnumDisplay;
-use inquire::error::InquireResult;
-use inquire_derive::Selectable;
-
-mod eight_day;
-mod eleventh_day;
-mod fifth_day;
-mod first_day;
-mod fourth_day;
-mod ninth_day;
-mod second_day;
-mod seventh_day;
-mod sixth_day;
-mod tenth_day;
-mod third_day;
-
-#[derive(Debug, Clone, Copy, Selectable, EnumDisplay)]
-enum DayOption {
-    First,
-    Second,
-    Third,
-    Fourth,
-    Fifth,
-    Sixth,
-    Seventh,
-    Eight,
-    Ninth,
-    Tenth,
-    Eleventh,
+use std::{
+    fmt::Display,
+    fs,
+    io::{IsTerminal, stdout},
+    path::Path,
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::Parser;
+use dpc_pariter::IteratorExt;
+use inquire::Select;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use advent_of_code_2025::{
+    benchmark::{Baseline, benchmark_day, format_report},
+    examples::{check_example, discover_examples},
+    fetcher::InputFetcher,
+    first_day::{self, Dial, FirstDay, TraceFormat, format_trace, trace_dial_from},
+    generator,
+    input::{InputCache, InputSource},
+    registry::{self, DAYS, Day},
+    report::{OutputFormat, RunReport, format_summary_as},
+    scaffold::create_day,
+    solution::{Answer, Part, Solution},
+    submission::{AnswerSubmitter, submit_answer},
+    verification::{self, AnswersFile, verify_day},
+    visualization::{
+        self, CLEAR_TERMINAL, DEFAULT_FRAME_DELAY, Renderer, render_terminal, write_svg_frames,
+    },
+    watch::{FileWatcher, format_changes},
+};
+
+use crate::cli::{Cli, Command};
+
+mod cli;
+
+enum MenuOption {
+    Day(&'static Day),
     Exit,
 }
 
-fn main() -> InquireResult<()> {
-    let mut prompt_for_selection = true;
-    while prompt_for_selection {
-        let day = DayOption::select("Select the day, or exit").prompt()?;
+impl Display for MenuOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuOption::Day(day) => write!(f, "{}", day),
+            MenuOption::Exit => write!(f, "Exit"),
+        }
+    }
+}
+
+fn show_visualization(day: &Day, input: &str, renderer: Renderer, directory: &Path) -> Result<()> {
+    let frames = visualization::visualize(day, input)?;
+    match renderer {
+        Renderer::Terminal => {
+            // The frames are played in place in a terminal, and printed one after the other
+            // without colors otherwise
+            let is_terminal = stdout().is_terminal();
+            for (index, frame) in frames.iter().enumerate() {
+                if is_terminal && index > 0 {
+                    thread::sleep(DEFAULT_FRAME_DELAY);
+                    print!("{}", CLEAR_TERMINAL);
+                }
+                println!("{}", render_terminal(frame, is_terminal));
+            }
+        }
+        Renderer::Svg => {
+            let paths = write_svg_frames(&frames, directory, day.number)?;
+            println!("Wrote {} frames to {}", paths.len(), directory.display());
+        }
+    }
+    Ok(())
+}
+
+fn run_day(
+    day: &Day,
+    input_source: &InputSource,
+    part: Part,
+    output_format: OutputFormat,
+    budget: Option<Duration>,
+    visualization: Option<(Renderer, &Path)>,
+) -> Result<()> {
+    // The input is read once, as it may come from stdin
+    let input = input_source.read();
+    // The answers are still reported when the picture cannot be drawn
+    if let (Some((renderer, directory)), Ok(input)) = (visualization, &input)
+        && let Err(error) = show_visualization(day, input, renderer, directory)
+    {
+        eprintln!("Could not visualize day {}: {:#}", day.number, error);
+    }
+    let report = RunReport::new(day, &input_source.to_string(), input, part, budget);
+    println!("{}", report.format(output_format)?);
+    if report.is_failure() {
+        bail!("Day {} failed", day.number)
+    }
+    Ok(())
+}
+
+fn run_interactive(
+    cache: &InputCache,
+    output_format: OutputFormat,
+    budget: Option<Duration>,
+) -> Result<()> {
+    loop {
+        let options = DAYS
+            .iter()
+            .map(MenuOption::Day)
+            .chain([MenuOption::Exit])
+            .collect();
+        match Select::new("Select the day, or exit", options).prompt()? {
+            MenuOption::Exit => return Ok(()),
+            MenuOption::Day(day) => {
+                // Report the error and go back to the menu so another day can be selected
+                if let Err(error) = run_day(
+                    day,
+                    &InputSource::resolve(day, None, cache),
+                    Part::Both,
+                    output_format,
+                    budget,
+                    None,
+                ) {
+                    eprintln!("Error: {:#}", error);
+                }
+            }
+        }
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+    cache: &InputCache,
+    output_format: OutputFormat,
+    budget: Option<Duration>,
+    visualization: Option<(Renderer, &Path)>,
+) -> Result<()> {
+    let day = registry::get_day(day).ok_or_else(|| anyhow!("Day {} is not registered", day))?;
+    let input_source = InputSource::resolve(day, input.as_deref(), cache);
+    run_day(
+        day,
+        &input_source,
+        Part::from_number(part),
+        output_format,
+        budget,
+        visualization,
+    )
+}
+
+fn check_examples(day: &Day, examples_directory: &Path) {
+    match discover_examples(examples_directory, day.number) {
+        Ok(examples) => {
+            for example in examples {
+                match check_example(day, &example) {
+                    Ok(()) => println!("Example {}: pass", example.name),
+                    Err(error) => println!("Example {}: FAIL ({:#})", example.name, error),
+                }
+            }
+        }
+        Err(error) => eprintln!("Error: {:#}", error),
+    }
+}
 
-        match day {
-            DayOption::Exit => prompt_for_selection = false,
-            DayOption::First => first_day::main(),
-            DayOption::Second => second_day::main(),
-            DayOption::Third => third_day::main(),
-            DayOption::Fourth => fourth_day::main(),
-            DayOption::Fifth => fifth_day::main(),
-            DayOption::Sixth => sixth_day::main(),
-            DayOption::Seventh => seventh_day::main(),
-            DayOption::Eight => eight_day::main(),
-            DayOption::Ninth => ninth_day::main(),
-            DayOption::Tenth => tenth_day::main(),
-            DayOption::Eleventh => eleventh_day::main(),
+// Runs the day every time a watched file changes, until the command is interrupted.
+// The answers are compared with the last run which could parse the input.
+fn watch(
+    day: u8,
+    input: Option<String>,
+    examples_directory: Option<&Path>,
+    interval: Duration,
+    cache: &InputCache,
+    budget: Option<Duration>,
+) -> Result<()> {
+    let day = registry::get_day(day).ok_or_else(|| anyhow!("Day {} is not registered", day))?;
+    let input_source = InputSource::resolve(day, input.as_deref(), cache);
+    let input_path = input_source
+        .path()
+        .ok_or_else(|| anyhow!("The input of a watched day cannot be read from stdin"))?;
+    let mut watched_paths = vec![input_path];
+    if let Some(examples_directory) = examples_directory {
+        for example in discover_examples(examples_directory, day.number)? {
+            watched_paths.push(example.input_path.with_extension("toml"));
+            watched_paths.push(example.input_path);
+        }
+    }
+    let mut watcher = FileWatcher::new(watched_paths);
+    println!(
+        "Watching {}",
+        watcher
+            .paths()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+    let mut previous_report = None;
+    loop {
+        let report = RunReport::new(
+            day,
+            &input_source.to_string(),
+            input_source.read(),
+            Part::Both,
+            budget,
+        );
+        println!("{}", format_changes(previous_report.as_ref(), &report));
+        if report.error.is_none() {
+            previous_report = Some(report);
+        }
+        if let Some(examples_directory) = examples_directory {
+            check_examples(day, examples_directory);
         }
+        let changed_files = loop {
+            thread::sleep(interval);
+            let changed_files = watcher.changed_files();
+            if !changed_files.is_empty() {
+                break changed_files;
+            }
+        };
+        println!(
+            "\nChanged {}",
+            changed_files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+}
+
+// The days are run concurrently, the reports are still collected in day order
+fn run_all(
+    cache: &InputCache,
+    output_format: OutputFormat,
+    budget: Option<Duration>,
+) -> Result<()> {
+    let cache = cache.clone();
+    let reports = DAYS
+        .iter()
+        .parallel_map(move |day| {
+            let input_source = InputSource::resolve(day, None, &cache);
+            RunReport::new(
+                day,
+                &input_source.to_string(),
+                input_source.read(),
+                Part::Both,
+                budget,
+            )
+        })
+        .collect::<Vec<RunReport>>();
+    println!("{}", format_summary_as(&reports, output_format)?);
+    let n_failures = reports.iter().filter(|report| report.is_failure()).count();
+    if n_failures > 0 {
+        bail!("{} day(s) failed", n_failures)
     }
     Ok(())
 }
+
+// Days that cannot be benchmarked, e.g because their input is missing, are reported and skipped
+fn run_benchmark(
+    day: Option<u8>,
+    n_runs: usize,
+    baseline_path: Option<&Path>,
+    save_baseline_path: Option<&Path>,
+    cache: &InputCache,
+) -> Result<()> {
+    let days = get_days(day)?;
+    let baseline = baseline_path.map(Baseline::load).transpose()?;
+    let mut benchmarks = Vec::new();
+    for day in days {
+        let input_source = InputSource::resolve(day, None, cache);
+        match input_source
+            .read()
+            .and_then(|input| benchmark_day(day, &input, n_runs))
+        {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(error) => eprintln!("Skipping day {}: {:#}", day.number, error),
+        }
+    }
+    println!("{}", format_report(&benchmarks, baseline.as_ref()));
+    if let Some(path) = save_baseline_path {
+        Baseline::from_benchmarks(&benchmarks).save(path)?;
+    }
+    Ok(())
+}
+
+fn get_days(day: Option<u8>) -> Result<Vec<&'static Day>> {
+    match day {
+        Some(day) => Ok(vec![
+            registry::get_day(day).ok_or_else(|| anyhow!("Day {} is not registered", day))?,
+        ]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+fn run_verification(day: Option<u8>, answers_path: &Path, cache: &InputCache) -> Result<()> {
+    let answers = AnswersFile::load(answers_path)?;
+    let verifications = get_days(day)?
+        .into_iter()
+        .map(|day| {
+            let input = InputSource::resolve(day, None, cache).read();
+            verify_day(day, input, answers.get(day.number))
+        })
+        .collect::<Vec<_>>();
+    println!("{}", verification::format_report(&verifications));
+    let n_failures = verifications
+        .iter()
+        .filter(|verification| verification.is_failure())
+        .count();
+    if n_failures > 0 {
+        bail!("{} day(s) failed the verification", n_failures)
+    }
+    Ok(())
+}
+
+// Inputs already in the cache are not downloaded again
+fn fetch_inputs(day: Option<u8>, cache: &InputCache) -> Result<()> {
+    if cache.fetcher.is_none() {
+        bail!("A session token is required to download the inputs")
+    }
+    for day in get_days(day)? {
+        cache.get(day.number)?;
+        println!("Day {}: {}", day.number, cache.path(day.number).display());
+    }
+    Ok(())
+}
+
+// The answer is computed from the input of the day when it is not provided
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    history_path: &Path,
+    cooldown: Duration,
+    submitter: Option<&AnswerSubmitter>,
+    cache: &InputCache,
+) -> Result<()> {
+    let submitter =
+        submitter.ok_or_else(|| anyhow!("A session token is required to submit an answer"))?;
+    let answer = match answer {
+        Some(answer) => answer
+            .parse::<usize>()
+            .map_or(Answer::Text(answer), Answer::Number),
+        None => {
+            let day =
+                registry::get_day(day).ok_or_else(|| anyhow!("Day {} is not registered", day))?;
+            let input = InputSource::resolve(day, None, cache).read()?;
+            let answers = day.run(&input, Part::from_number(Some(part)))?;
+            if part == 1 {
+                answers.first
+            } else {
+                answers.second
+            }
+            .ok_or_else(|| anyhow!("Day {} part {} has no answer", day.number, part))?
+        }
+    };
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let verdict = submit_answer(submitter, history_path, day, part, answer, cooldown)?;
+    println!("{}", verdict);
+    Ok(())
+}
+
+// The phases are logged with their duration when they end
+fn init_tracing(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn generate_input(day: u8, seed: u64, size: usize, output_path: Option<&Path>) -> Result<()> {
+    let day = registry::get_day(day).ok_or_else(|| anyhow!("Day {} is not registered", day))?;
+    let input = generator::generate(day, seed, size)?;
+    match output_path {
+        Some(path) => fs::write(path, input)
+            .with_context(|| format!("Could not write the input to {}", path.display())),
+        None => {
+            println!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn trace_dial(
+    input: Option<String>,
+    size: usize,
+    starting_position: usize,
+    format: TraceFormat,
+    output_path: Option<&Path>,
+    cache: &InputCache,
+) -> Result<()> {
+    let input = InputSource::resolve(&first_day::DAY, input.as_deref(), cache).read()?;
+    let instructions = FirstDay::parse(&input)?;
+    let dial = Dial::with_size(size, starting_position)?;
+    let rotations = dial.resolve(&instructions)?;
+    let trace = format_trace(&trace_dial_from(dial, &rotations)?, format)?;
+    match output_path {
+        Some(path) => fs::write(path, trace)
+            .with_context(|| format!("Could not write the trace to {}", path.display())),
+        None => {
+            println!("{}", trace);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+    let fetcher = cli
+        .session
+        .as_deref()
+        .map(|session| InputFetcher::new(&cli.base_url, session));
+    let cache = InputCache::new(cli.input_dir.as_deref(), fetcher);
+    let submitter = cli
+        .session
+        .as_deref()
+        .map(|session| AnswerSubmitter::new(&cli.base_url, session));
+    let budget = cli.timeout.map(Duration::from_secs);
+    let result = match cli.command {
+        None => run_interactive(&cache, cli.format, budget),
+        Some(Command::Run {
+            day,
+            part,
+            input,
+            visualize,
+            visualization_dir,
+        }) => run(
+            day,
+            part,
+            input,
+            &cache,
+            cli.format,
+            budget,
+            visualize.map(|renderer| (renderer, visualization_dir.as_path())),
+        ),
+        Some(Command::Watch {
+            day,
+            input,
+            examples,
+            examples_dir,
+            interval,
+        }) => watch(
+            day,
+            input,
+            examples.then_some(examples_dir.as_path()),
+            Duration::from_millis(interval),
+            &cache,
+            budget,
+        ),
+        Some(Command::RunAll) => run_all(&cache, cli.format, budget),
+        Some(Command::Fetch { day }) => fetch_inputs(day, &cache),
+        Some(Command::Bench {
+            day,
+            runs,
+            baseline,
+            save_baseline,
+        }) => run_benchmark(
+            day,
+            runs as usize,
+            baseline.as_deref(),
+            save_baseline.as_deref(),
+            &cache,
+        ),
+        Some(Command::Verify { day, answers }) => run_verification(day, &answers, &cache),
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            history,
+            cooldown,
+        }) => submit(
+            day,
+            part,
+            answer,
+            &history,
+            Duration::from_secs(cooldown),
+            submitter.as_ref(),
+            &cache,
+        ),
+        Some(Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        }) => generate_input(day, seed, size, output.as_deref()),
+        Some(Command::Trace {
+            input,
+            size,
+            start,
+            export,
+            output,
+        }) => trace_dial(input, size, start, export, output.as_deref(), &cache),
+        Some(Command::NewDay {
+            day,
+            title,
+            source_dir,
+        }) => create_day(&source_dir, day, &title).map(|module_directory| {
+            println!("Day {} created in {}", day, module_directory.display())
+        }),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {:#}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
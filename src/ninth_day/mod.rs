@@ -1,21 +1,34 @@
+//! Day 9: Movie Theater, finding the largest rectangle drawn between red tiles.
+
 use std::{
     cmp::{max, min},
     collections::{HashMap, HashSet},
     fmt::Display,
-    fs::read_to_string,
+    num::ParseIntError,
+    ops::RangeInclusive,
 };
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use itertools::{Itertools, iproduct};
+use rand::RngExt;
 use thiserror::Error;
 
+use crate::{
+    generator::InputRng,
+    parsing::{LineError, parse_lines},
+    registry::Day,
+    solution::Solution,
+    visualization::{CANVAS_HEIGHT, CANVAS_WIDTH, Color, Picture, Shape, fit_to_canvas},
+};
+
 #[cfg(test)]
 mod tests;
 
+/// A red tile of the floor, written as `x,y` in the input.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct RedTile {
-    x: usize,
-    y: usize,
+pub struct RedTile {
+    pub x: usize,
+    pub y: usize,
 }
 
 impl Display for RedTile {
@@ -24,25 +37,38 @@ impl Display for RedTile {
     }
 }
 
-impl From<&str> for RedTile {
-    fn from(value: &str) -> Self {
-        let split_str = value.split(",").take(2).collect_vec();
-        Self {
-            x: split_str[0]
-                .parse()
-                .expect("The x coordinate to represent an integer"),
-            y: split_str[1]
-                .parse()
-                .expect("The y coordinate to represent an integer"),
+#[derive(Error, Debug)]
+pub enum RedTileParsingError {
+    #[error("Expected 2 comma separated coordinates, got {0}")]
+    InvalidNumberOfCoordinates(usize),
+    #[error("Invalid coordinate")]
+    InvalidCoordinate(#[from] ParseIntError),
+}
+
+impl TryFrom<&str> for RedTile {
+    type Error = RedTileParsingError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let split_str = value.split(",").collect_vec();
+        if let [x, y] = split_str[..] {
+            Ok(Self {
+                x: x.parse()?,
+                y: y.parse()?,
+            })
+        } else {
+            Err(RedTileParsingError::InvalidNumberOfCoordinates(
+                split_str.len(),
+            ))
         }
     }
 }
 
-fn parse_input(input: String) -> Vec<RedTile> {
-    input.lines().map(RedTile::from).collect()
+pub fn parse_input(input: String) -> Result<Vec<RedTile>, LineError<RedTileParsingError>> {
+    parse_lines(&input, |line| RedTile::try_from(line))
 }
 
-fn get_tile_combinations(tiles: &Vec<RedTile>) -> Vec<(RedTile, RedTile)> {
+/// Every pair of distinct red tiles.
+pub fn get_tile_combinations(tiles: &Vec<RedTile>) -> Vec<(RedTile, RedTile)> {
     iproduct!(tiles.iter().enumerate(), tiles.iter().enumerate())
         .filter_map(|((i, tile_a), (j, tile_b))| {
             if i < j {
@@ -54,7 +80,8 @@ fn get_tile_combinations(tiles: &Vec<RedTile>) -> Vec<(RedTile, RedTile)> {
         .collect()
 }
 
-fn calculate_area(tile_a: &RedTile, tile_b: &RedTile) -> usize {
+/// Area of the rectangle having the two tiles as opposite corners, including the corners.
+pub fn calculate_area(tile_a: &RedTile, tile_b: &RedTile) -> usize {
     let width = (tile_a.x as isize - tile_b.x as isize).abs() + 1;
     let length = (tile_a.y as isize - tile_b.y as isize).abs() + 1;
     (width * length) as usize
@@ -302,13 +329,16 @@ impl LineGreenIntervals {
     }
 }
 
+/// The figure drawn by the red tiles and the green tiles between them, stored as the
+/// intervals of green or red tiles of each line containing a red tile.
 #[derive(Debug)]
-struct FigureIntervals {
+pub struct FigureIntervals {
     lines: HashMap<usize, LineGreenIntervals>,
 }
 
 impl FigureIntervals {
-    fn new(tiles: &Vec<RedTile>) -> Result<Self> {
+    /// Build the figure from the red tiles, in the order they are connected.
+    pub fn new(tiles: &Vec<RedTile>) -> Result<Self> {
         let mut output_lines = HashMap::new();
         let mut sorted_tiles = tiles
             .clone()
@@ -342,7 +372,7 @@ impl FigureIntervals {
         })
     }
 
-    fn rectangle_is_within_figure(&self, tile_a: &RedTile, tile_b: &RedTile) -> bool {
+    pub fn rectangle_is_within_figure(&self, tile_a: &RedTile, tile_b: &RedTile) -> bool {
         let start_x = min(tile_a.x, tile_b.x);
         let end_x = max(tile_a.x, tile_b.x);
         let start_y = min(tile_a.y, tile_b.y);
@@ -363,27 +393,147 @@ impl FigureIntervals {
     }
 }
 
-pub fn main() {
-    let input =
-        parse_input(read_to_string("data/day_9.txt").expect("The data file to be readable"));
-    let biggest_surface = get_tile_combinations(&input)
+// Random coordinate in the range, different from the previous one so consecutive columns do
+// not share their edges
+fn random_height(
+    rng: &mut InputRng,
+    range: RangeInclusive<usize>,
+    previous: Option<&usize>,
+) -> usize {
+    loop {
+        let height = rng.random_range(range.clone());
+        if previous != Some(&height) {
+            return height;
+        }
+    }
+}
+
+/// Random rectilinear polygon drawn as the outline of adjacent columns of random heights.
+/// Every column spans the middle row so the polygon is in one piece. The size is the number
+/// of columns, each one adds 4 red tiles.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    const HEIGHT: usize = 100_000;
+    let mut xs = vec![rng.random_range(0..1000)];
+    let mut tops = Vec::new();
+    let mut bottoms = Vec::new();
+    for column in 0..size {
+        xs.push(xs[column] + rng.random_range(1..=1000));
+        let top = random_height(rng, HEIGHT / 2 + 1..=HEIGHT, tops.last());
+        tops.push(top);
+        let bottom = random_height(rng, 0..=HEIGHT / 2 - 1, bottoms.last());
+        bottoms.push(bottom);
+    }
+    // The top edges from left to right, then the bottom edges back to the first column
+    let mut tiles = Vec::new();
+    for (column, &top) in tops.iter().enumerate() {
+        tiles.push((xs[column], top));
+        tiles.push((xs[column + 1], top));
+    }
+    for (column, &bottom) in bottoms.iter().enumerate().rev() {
+        tiles.push((xs[column + 1], bottom));
+        tiles.push((xs[column], bottom));
+    }
+    tiles
         .iter()
-        .map(|(a, b)| calculate_area(a, b))
-        .max()
-        .expect("That there are some possible rectangles");
-    println!("The biggest surface available is {}", biggest_surface);
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
 
-    // Second part of the problem is identical, but we have to filter out the elements that are inside the expected area
-    let figure = FigureIntervals::new(&input).expect("The figure to be correctly created");
-    let biggest_rectangle = get_tile_combinations(&input)
+/// Picture of the figure drawn by the red tiles, filled with the green tiles, and of the
+/// largest rectangle within it.
+pub fn visualize(input: &str) -> Result<Vec<Picture>> {
+    let tiles = parse_input(input.to_string())?;
+    let points = fit_to_canvas(
+        &tiles
+            .iter()
+            .map(|tile| (tile.x, tile.y))
+            .collect::<Vec<(usize, usize)>>(),
+        CANVAS_WIDTH,
+        CANVAS_HEIGHT,
+    );
+    let point_of_tile = tiles
         .iter()
+        .map(|tile| (tile.x, tile.y))
+        .zip(points.iter().copied())
+        .collect::<HashMap<(usize, usize), (usize, usize)>>();
+    let figure = FigureIntervals::new(&tiles)?;
+    let largest_rectangle = get_tile_combinations(&tiles)
+        .into_iter()
         .sorted_by_key(|(tile_a, tile_b)| calculate_area(tile_a, tile_b))
         .rev()
-        .find(|(tile_a, tile_b)| figure.rectangle_is_within_figure(tile_a, tile_b))
-        .expect("There is at least one valid rectangle")
-        .clone();
-    println!(
-        "The biggest rectangle is of area {}",
-        calculate_area(&biggest_rectangle.0, &biggest_rectangle.1)
+        .find(|(tile_a, tile_b)| figure.rectangle_is_within_figure(tile_a, tile_b));
+
+    let mut picture = Picture::new(
+        match &largest_rectangle {
+            Some((tile_a, tile_b)) => format!(
+                "Largest rectangle within the figure: {} tiles",
+                calculate_area(tile_a, tile_b)
+            ),
+            None => String::from("No rectangle within the figure"),
+        },
+        CANVAS_WIDTH,
+        CANVAS_HEIGHT,
     );
+    picture.push(Shape::Polygon {
+        points: points.clone(),
+        color: Color::Green,
+    });
+    if let Some((tile_a, tile_b)) = largest_rectangle {
+        picture.push(Shape::Rectangle {
+            corner_a: point_of_tile[&(tile_a.x, tile_a.y)],
+            corner_b: point_of_tile[&(tile_b.x, tile_b.y)],
+            color: Color::Yellow,
+        });
+    }
+    for point in points {
+        picture.push(Shape::Cell {
+            at: point,
+            glyph: '#',
+            color: Color::Red,
+        });
+    }
+    Ok(vec![picture])
+}
+
+/// Part one finds the largest rectangle between two red tiles, part two the largest one
+/// that stays within the figure.
+pub struct NinthDay;
+
+impl Solution for NinthDay {
+    type Input = Vec<RedTile>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input.to_string())?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        get_tile_combinations(input)
+            .iter()
+            .map(|(a, b)| calculate_area(a, b))
+            .max()
+            .ok_or_else(|| anyhow!("No rectangle can be drawn from the red tiles"))
+    }
+
+    // Second part of the problem is identical, but we have to filter out the elements that are inside the expected area
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        let figure = FigureIntervals::new(input)?;
+        get_tile_combinations(input)
+            .iter()
+            .sorted_by_key(|(tile_a, tile_b)| calculate_area(tile_a, tile_b))
+            .rev()
+            .find(|(tile_a, tile_b)| figure.rectangle_is_within_figure(tile_a, tile_b))
+            .map(|(tile_a, tile_b)| calculate_area(tile_a, tile_b))
+            .ok_or_else(|| anyhow!("No rectangle fits within the figure"))
+    }
 }
+
+pub const DAY: Day = Day {
+    number: 9,
+    title: "Movie Theater",
+    solver: &NinthDay,
+    generator: Some(generate_input),
+    visualizer: Some(visualize),
+};
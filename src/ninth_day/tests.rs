@@ -2,7 +2,8 @@ use std::{
     collections::HashSet,
     fs::read_to_string
 };
-use itertools::Itertools;
+use itertools::{Itertools, iproduct};
+use proptest::prelude::*;
 use test_case::test_case;
 use crate::ninth_day::{
     FigureIntervals, GreenInterval, LineGreenIntervals, RedTile, calculate_area, get_tile_combinations, parse_input
@@ -19,7 +20,7 @@ static INPUT: &str = "7,1
 
 #[test]
 fn test_example_first_case() {
-    let input = parse_input(INPUT.to_string());
+    let input = parse_input(INPUT.to_string()).expect("The input to be correctly parsed");
     let biggest_line = get_tile_combinations(&input)
         .into_iter()
         .map(|(a, b)| calculate_area(&a, &b))
@@ -33,7 +34,8 @@ fn input_data_does_not_have_three_consecutive_red_tiles_aligned() {
     // Test that the input data does not have three consecutives red tiles aligned
     // If that is the case, this would simplify the way we can draw the green tile area
     let input =
-        parse_input(read_to_string("data/day_9.txt").expect("The data file to be correctly read."));
+        parse_input(read_to_string("data/day_9.txt").expect("The data file to be correctly read."))
+            .expect("The input to be correctly parsed");
     let mut input_iter = input.into_iter();
     let mut previous_tile = input_iter
         .next()
@@ -252,7 +254,7 @@ fn test_multi_merge_on_same_line(tiles: Vec<RedTile>, current_intervals: HashSet
 
 #[test]
 fn test_example_second_case() {
-    let input = parse_input(INPUT.to_string());
+    let input = parse_input(INPUT.to_string()).expect("The input to be correctly parsed");
     let figure = FigureIntervals::new(&input).expect("The figure to be correctly created");
     dbg!(&figure.lines);
     let biggest_rectangle = get_tile_combinations(&input)
@@ -266,4 +268,115 @@ fn test_example_second_case() {
     assert_eq!(calculate_area(&tile_a, &tile_b), 24)
 }
 
+// Reference largest rectangle within the figure, drawn on a grid of the compressed
+// coordinates of the red tiles: each coordinate and each gap between two coordinates is a
+// cell, the cells outside of the outline are filled from the border of the grid
+fn largest_rectangle_within_compressed_figure(tiles: &[RedTile]) -> usize {
+    let xs = tiles
+        .iter()
+        .map(|tile| tile.x)
+        .sorted()
+        .dedup()
+        .collect::<Vec<usize>>();
+    let ys = tiles
+        .iter()
+        .map(|tile| tile.y)
+        .sorted()
+        .dedup()
+        .collect::<Vec<usize>>();
+    let cell = |coordinates: &[usize], value: usize| {
+        2 * coordinates
+            .binary_search(&value)
+            .expect("The coordinate to be compressed")
+            + 1
+    };
+    let (width, height) = (2 * xs.len() + 1, 2 * ys.len() + 1);
+    let mut outline = vec![vec![false; height]; width];
+    for (tile_a, tile_b) in tiles.iter().circular_tuple_windows() {
+        let (x_a, x_b) = (cell(&xs, tile_a.x), cell(&xs, tile_b.x));
+        let (y_a, y_b) = (cell(&ys, tile_a.y), cell(&ys, tile_b.y));
+        for column in &mut outline[x_a.min(x_b)..=x_a.max(x_b)] {
+            column[y_a.min(y_b)..=y_a.max(y_b)].fill(true);
+        }
+    }
+    let mut outside = vec![vec![false; height]; width];
+    let mut cells_to_fill = vec![(0, 0)];
+    while let Some((x, y)) = cells_to_fill.pop() {
+        if outside[x][y] || outline[x][y] {
+            continue;
+        }
+        outside[x][y] = true;
+        cells_to_fill.extend(
+            [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ]
+            .into_iter()
+            .filter(|&(x, y)| x < width && y < height),
+        );
+    }
+    tiles
+        .iter()
+        .tuple_combinations()
+        .filter(|(tile_a, tile_b)| {
+            let (x_a, x_b) = (cell(&xs, tile_a.x), cell(&xs, tile_b.x));
+            let (y_a, y_b) = (cell(&ys, tile_a.y), cell(&ys, tile_b.y));
+            iproduct!(x_a.min(x_b)..=x_a.max(x_b), y_a.min(y_b)..=y_a.max(y_b))
+                .all(|(x, y)| !outside[x][y])
+        })
+        .map(|(tile_a, tile_b)| calculate_area(tile_a, tile_b))
+        .max()
+        .expect("A rectangle to be within the figure")
+}
+
+// Figure drawn like the generated inputs, from columns of random heights, with small
+// coordinates as the figure is scanned line by line
+fn small_figure() -> impl Strategy<Value = Vec<RedTile>> {
+    prop::collection::vec((1..=5usize, 11..=20usize, 0..=9usize), 1..8)
+        .prop_filter("Consecutive columns share an edge", |columns| {
+            columns
+                .iter()
+                .tuple_windows()
+                .all(|(left, right)| left.1 != right.1 && left.2 != right.2)
+        })
+        .prop_map(|columns| {
+            let xs = columns
+                .iter()
+                .scan(0, |x, &(width, _, _)| {
+                    *x += width;
+                    Some(*x)
+                })
+                .collect::<Vec<usize>>();
+            let tops = columns
+                .iter()
+                .enumerate()
+                .flat_map(|(column, &(width, top, _))| {
+                    [(xs[column] - width, top), (xs[column], top)]
+                });
+            let bottoms =
+                columns
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .flat_map(|(column, &(width, _, bottom))| {
+                        [(xs[column], bottom), (xs[column] - width, bottom)]
+                    });
+            tops.chain(bottoms).map(|(x, y)| RedTile { x, y }).collect()
+        })
+}
 
+proptest! {
+
+    #[test]
+    fn test_largest_rectangle_matches_compressed_figure(tiles in small_figure()) {
+        let figure = FigureIntervals::new(&tiles).expect("The figure to be built");
+        let largest_area = get_tile_combinations(&tiles)
+            .iter()
+            .filter(|(tile_a, tile_b)| figure.rectangle_is_within_figure(tile_a, tile_b))
+            .map(|(tile_a, tile_b)| calculate_area(tile_a, tile_b))
+            .max();
+        prop_assert_eq!(largest_area, Some(largest_rectangle_within_compressed_figure(&tiles)));
+    }
+}
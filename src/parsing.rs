@@ -0,0 +1,39 @@
+use std::error::Error as StdError;
+
+use thiserror::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// Error raised when a line of an input cannot be parsed, keeping track of where it happened.
+#[derive(Error, Debug)]
+#[error("Invalid line {line_number}: {line:?}")]
+pub struct LineError<E: StdError + 'static> {
+    pub line_number: usize,
+    pub line: String,
+    #[source]
+    pub error: E,
+}
+
+impl<E: StdError + 'static> LineError<E> {
+    /// Line numbers start at 1, as in a text editor
+    pub fn new(line_index: usize, line: &str, error: E) -> Self {
+        LineError {
+            line_number: line_index + 1,
+            line: line.to_string(),
+            error,
+        }
+    }
+}
+
+/// Parse every line of the input, stopping at the first line that cannot be parsed.
+pub fn parse_lines<T, E: StdError + 'static>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, LineError<E>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parser(line).map_err(|error| LineError::new(index, line, error)))
+        .collect()
+}
@@ -0,0 +1,24 @@
+use crate::parsing::parse_lines;
+
+#[test]
+fn test_parse_valid_lines() {
+    let parsed = parse_lines("1\n2\n3", |line| line.parse::<usize>());
+    assert_eq!(parsed.expect("The lines to be parsed"), vec![1, 2, 3])
+}
+
+#[test]
+fn test_invalid_line_is_reported() {
+    let error = parse_lines("1\n2\nthree\n4", |line| line.parse::<usize>())
+        .expect_err("The third line to be invalid");
+    assert_eq!(error.line_number, 3);
+    assert_eq!(error.line, "three");
+    assert_eq!(error.to_string(), "Invalid line 3: \"three\"");
+}
+
+#[test]
+fn test_first_invalid_line_is_reported() {
+    let error =
+        parse_lines("a\nb", |line| line.parse::<usize>()).expect_err("The lines to be invalid");
+    assert_eq!(error.line_number, 1);
+    assert_eq!(error.line, "a");
+}
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use tracing::info_span;
+
+use crate::{
+    generator::InputGenerator,
+    solution::{Answer, DaySolver, Part},
+    visualization::Visualizer,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A day registered in the runner, each day module exposes its own `DAY` constant.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub solver: &'static dyn DaySolver,
+    pub generator: Option<InputGenerator>,
+    pub visualizer: Option<Visualizer>,
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {}: {}", self.number, self.title)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub first: Option<Answer>,
+    pub second: Option<Answer>,
+}
+
+impl Day {
+    pub fn run(&self, input: &str, part: Part) -> Result<DayAnswers> {
+        let _span = info_span!("day", number = self.number).entered();
+        let parsed_input = self.solver.parse(input)?;
+        let mut answers = DayAnswers::default();
+        if part.includes_first() {
+            answers.first = Some(self.solver.part_one(&parsed_input)?);
+        }
+        if part.includes_second() {
+            answers.second = Some(self.solver.part_two(&parsed_input)?);
+        }
+        Ok(answers)
+    }
+}
+
+/// Every registered day, in day order. The days are referred to by their full path so
+/// the `new-day` command can register a day by inserting a single line.
+pub static DAYS: &[Day] = &[
+    crate::first_day::DAY,
+    crate::second_day::DAY,
+    crate::third_day::DAY,
+    crate::fourth_day::DAY,
+    crate::fifth_day::DAY,
+    crate::sixth_day::DAY,
+    crate::seventh_day::DAY,
+    crate::eight_day::DAY,
+    crate::ninth_day::DAY,
+    crate::tenth_day::DAY,
+    crate::eleventh_day::DAY,
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
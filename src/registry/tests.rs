@@ -0,0 +1,53 @@
+use itertools::Itertools;
+
+use crate::{
+    registry::{DAYS, DayAnswers, get_day},
+    solution::{Answer, Part},
+};
+
+static FIRST_DAY_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+#[test]
+fn test_days_are_registered_once_in_order() {
+    let numbers = DAYS.iter().map(|day| day.number).collect_vec();
+    assert_eq!(numbers, (1..=11).collect_vec());
+}
+
+#[test]
+fn test_get_unregistered_day() {
+    assert!(get_day(25).is_none())
+}
+
+#[test]
+fn test_run_day_through_registry() {
+    let day = get_day(1).expect("The first day to be registered");
+    let answers = day
+        .run(FIRST_DAY_INPUT, Part::Both)
+        .expect("The example to be solved");
+    assert_eq!(
+        answers,
+        DayAnswers {
+            first: Some(Answer::Number(3)),
+            second: Some(Answer::Number(6)),
+        }
+    )
+}
+
+#[test]
+fn test_run_single_part_through_registry() {
+    let day = get_day(1).expect("The first day to be registered");
+    let answers = day
+        .run(FIRST_DAY_INPUT, Part::Second)
+        .expect("The example to be solved");
+    assert_eq!(answers.first, None);
+    assert_eq!(answers.second, Some(Answer::Number(6)));
+}
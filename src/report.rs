@@ -0,0 +1,234 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use tracing::info_span;
+
+use crate::{
+    budget::with_budget,
+    registry::Day,
+    solution::{Answer, ParsedInput, Part},
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub duration_ns: u64,
+    pub error: Option<String>,
+}
+
+/// Result of running a day on an input, errors raised while reading or parsing the input
+/// are reported on the run while the errors of each part are reported on the part.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct RunReport {
+    pub day: u8,
+    pub input: String,
+    pub parse_duration_ns: Option<u64>,
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn run_part(
+    part: u8,
+    parsed_input: &ParsedInput,
+    budget: Option<Duration>,
+    solve: impl FnOnce(&ParsedInput) -> Result<Answer>,
+) -> PartReport {
+    let start = Instant::now();
+    let result = with_budget(budget, || solve(parsed_input));
+    let duration_ns = as_nanos(start.elapsed());
+    match result {
+        Ok(answer) => PartReport {
+            part,
+            answer: Some(answer),
+            duration_ns,
+            error: None,
+        },
+        Err(error) => PartReport {
+            part,
+            answer: None,
+            duration_ns,
+            error: Some(format!("{:#}", error)),
+        },
+    }
+}
+
+impl RunReport {
+    /// The parsing and each part are given the time budget, if any, and fail when they
+    /// exhaust it.
+    pub fn new(
+        day: &Day,
+        input_name: &str,
+        input: Result<String>,
+        part: Part,
+        budget: Option<Duration>,
+    ) -> Self {
+        let _span = info_span!("day", number = day.number).entered();
+        let mut report = RunReport {
+            day: day.number,
+            input: input_name.to_string(),
+            parse_duration_ns: None,
+            error: None,
+            parts: Vec::new(),
+        };
+        let start = Instant::now();
+        let parsed_input =
+            match input.and_then(|input| with_budget(budget, || day.solver.parse(&input))) {
+                Ok(parsed_input) => parsed_input,
+                Err(error) => {
+                    report.error = Some(format!("{:#}", error));
+                    return report;
+                }
+            };
+        report.parse_duration_ns = Some(as_nanos(start.elapsed()));
+        if part.includes_first() {
+            report
+                .parts
+                .push(run_part(1, &parsed_input, budget, |input| {
+                    day.solver.part_one(input)
+                }));
+        }
+        if part.includes_second() {
+            report
+                .parts
+                .push(run_part(2, &parsed_input, budget, |input| {
+                    day.solver.part_two(input)
+                }));
+        }
+        report
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some() || self.parts.iter().any(|part| part.error.is_some())
+    }
+
+    pub fn to_text(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!(
+                "Day {} - could not use input {}: {}",
+                self.day, self.input, error
+            );
+        }
+        self.parts
+            .iter()
+            .map(|part| self.part_to_text(part))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Line of a single part of the run, with its answer and timing or its error.
+    pub fn part_to_text(&self, part: &PartReport) -> String {
+        match (&part.answer, &part.error) {
+            (Some(answer), _) => format!(
+                "Day {} - part {}: {} ({:.2?})",
+                self.day,
+                part.part,
+                answer,
+                Duration::from_nanos(part.duration_ns)
+            ),
+            (None, error) => format!(
+                "Day {} - part {} failed: {}",
+                self.day,
+                part.part,
+                error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+
+    // Only the errors of the run, listed under the summary of several days
+    fn failures_to_text(&self) -> String {
+        if self.error.is_some() {
+            return self.to_text();
+        }
+        self.parts
+            .iter()
+            .filter(|part| part.error.is_some())
+            .map(|part| self.part_to_text(part))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn format(&self, output_format: OutputFormat) -> Result<String> {
+        match output_format {
+            OutputFormat::Text => Ok(self.to_text()),
+            OutputFormat::Json => Ok(serde_json::to_string(self)?),
+        }
+    }
+}
+
+fn format_part(part: Option<&PartReport>) -> (String, String) {
+    match part {
+        Some(PartReport {
+            answer: Some(answer),
+            duration_ns,
+            ..
+        }) => (
+            answer.to_string(),
+            format!("{:.2?}", Duration::from_nanos(*duration_ns)),
+        ),
+        Some(_) => (String::from("failed"), String::new()),
+        None => (String::from("-"), String::new()),
+    }
+}
+
+/// Table of the answers and timings of several days, the errors are listed below the table.
+pub fn format_summary(reports: &[RunReport]) -> String {
+    let mut lines = vec![format!(
+        "{:<5}{:>20}{:>14}{:>20}{:>14}{:>14}",
+        "Day", "Part 1", "Time", "Part 2", "Time", "Total"
+    )];
+    for report in reports {
+        let (first_answer, first_duration) =
+            format_part(report.parts.iter().find(|part| part.part == 1));
+        let (second_answer, second_duration) =
+            format_part(report.parts.iter().find(|part| part.part == 2));
+        let total = report.parse_duration_ns.map(|parse_duration_ns| {
+            report.parts.iter().fold(parse_duration_ns, |total, part| {
+                total.saturating_add(part.duration_ns)
+            })
+        });
+        lines.push(format!(
+            "{:<5}{:>20}{:>14}{:>20}{:>14}{:>14}",
+            report.day,
+            first_answer,
+            first_duration,
+            second_answer,
+            second_duration,
+            total
+                .map(|total| format!("{:.2?}", Duration::from_nanos(total)))
+                .unwrap_or_default()
+        ));
+    }
+    let failures = reports
+        .iter()
+        .filter(|report| report.is_failure())
+        .collect::<Vec<_>>();
+    if !failures.is_empty() {
+        lines.push(String::new());
+        lines.extend(failures.iter().map(|report| report.failures_to_text()));
+    }
+    lines.join("\n")
+}
+
+pub fn format_summary_as(reports: &[RunReport], output_format: OutputFormat) -> Result<String> {
+    match output_format {
+        OutputFormat::Text => Ok(format_summary(reports)),
+        OutputFormat::Json => Ok(serde_json::to_string(reports)?),
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::anyhow;
+use serde_json::json;
+
+use crate::{
+    registry::get_day,
+    report::{OutputFormat, RunReport, format_summary, format_summary_as},
+    solution::{Answer, Part},
+};
+
+static FIRST_DAY_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+#[test]
+fn test_report_of_both_parts() {
+    let day = get_day(1).expect("The first day to be registered");
+    let report = RunReport::new(
+        day,
+        "example",
+        Ok(FIRST_DAY_INPUT.to_string()),
+        Part::Both,
+        None,
+    );
+    assert!(!report.is_failure());
+    assert!(report.parse_duration_ns.is_some());
+    assert_eq!(
+        report
+            .parts
+            .iter()
+            .map(|part| (part.part, part.answer.clone()))
+            .collect::<Vec<_>>(),
+        vec![(1, Some(Answer::Number(3))), (2, Some(Answer::Number(6)))]
+    );
+}
+
+#[test]
+fn test_report_of_invalid_input() {
+    let day = get_day(1).expect("The first day to be registered");
+    let report = RunReport::new(day, "example", Ok(String::from("X12")), Part::Both, None);
+    assert!(report.is_failure());
+    assert!(report.parts.is_empty());
+    assert_eq!(
+        report.error.as_deref(),
+        Some("Invalid line 1: \"X12\": Invalid direction, expected L, R or =, got 'X' at column 1")
+    );
+}
+
+#[test]
+fn test_json_report() {
+    let day = get_day(1).expect("The first day to be registered");
+    let report = RunReport::new(
+        day,
+        "missing.txt",
+        Err(anyhow!("missing input")),
+        Part::First,
+        None,
+    );
+    let json: serde_json::Value = serde_json::from_str(
+        &report
+            .format(OutputFormat::Json)
+            .expect("The report to be serialized"),
+    )
+    .expect("The report to be valid JSON");
+    assert_eq!(
+        json,
+        json!({
+            "day": 1,
+            "input": "missing.txt",
+            "parse_duration_ns": null,
+            "error": "missing input",
+            "parts": [],
+        })
+    );
+}
+
+#[test]
+fn test_json_answer_is_a_number() {
+    let day = get_day(1).expect("The first day to be registered");
+    let report = RunReport::new(
+        day,
+        "example",
+        Ok(FIRST_DAY_INPUT.to_string()),
+        Part::Second,
+        None,
+    );
+    let json: serde_json::Value = serde_json::from_str(
+        &report
+            .format(OutputFormat::Json)
+            .expect("The report to be serialized"),
+    )
+    .expect("The report to be valid JSON");
+    assert_eq!(json["parts"][0]["part"], 2);
+    assert_eq!(json["parts"][0]["answer"], 6);
+    assert_eq!(json["parts"][0]["error"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_summary_in_day_order() {
+    let day = get_day(1).expect("The first day to be registered");
+    let reports = vec![
+        RunReport::new(
+            day,
+            "example",
+            Ok(FIRST_DAY_INPUT.to_string()),
+            Part::Both,
+            None,
+        ),
+        RunReport::new(
+            day,
+            "missing.txt",
+            Err(anyhow!("missing input")),
+            Part::Both,
+            None,
+        ),
+    ];
+    let summary = format_summary(&reports);
+    let lines = summary.lines().collect::<Vec<&str>>();
+    assert!(lines[0].starts_with("Day"));
+    assert!(lines[1].starts_with("1"));
+    assert!(lines[1].contains(" 3 "));
+    assert!(lines[1].contains(" 6 "));
+    assert!(lines[2].contains("-"));
+    assert_eq!(
+        lines.last(),
+        Some(&"Day 1 - could not use input missing.txt: missing input")
+    );
+}
+
+#[test]
+fn test_json_summary() {
+    let day = get_day(1).expect("The first day to be registered");
+    let reports = vec![RunReport::new(
+        day,
+        "example",
+        Ok(FIRST_DAY_INPUT.to_string()),
+        Part::Both,
+        None,
+    )];
+    let json: serde_json::Value = serde_json::from_str(
+        &format_summary_as(&reports, OutputFormat::Json).expect("The summary to be serialized"),
+    )
+    .expect("The summary to be valid JSON");
+    assert_eq!(json[0]["parts"][0]["answer"], 3);
+    assert_eq!(json[0]["parts"][1]["answer"], 6);
+}
@@ -0,0 +1,249 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_SOURCE_DIRECTORY: &str = "src";
+
+// Ordinals used to name the day modules, the eighth day module was historically named eight_day
+const ORDINALS: [&str; 25] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eight",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+    "twenty_first",
+    "twenty_second",
+    "twenty_third",
+    "twenty_fourth",
+    "twenty_fifth",
+];
+
+const MODULE_TEMPLATE: &str = r#"//! Day {number}: {title}.
+
+use anyhow::{Result, bail};
+
+use crate::{registry::Day, solution::Solution};
+
+#[cfg(test)]
+mod tests;
+
+/// Parse the input, one element per line.
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
+
+pub fn solve_part_one(input: &[String]) -> Result<usize> {
+    bail!("Part one is not solved yet, got {} lines", input.len())
+}
+
+pub fn solve_part_two(input: &[String]) -> Result<usize> {
+    bail!("Part two is not solved yet, got {} lines", input.len())
+}
+
+pub struct {solver};
+
+impl Solution for {solver} {
+    type Input = Vec<String>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        solve_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        solve_part_two(input)
+    }
+}
+
+pub const DAY: Day = Day {
+    number: {number},
+    title: {title_literal},
+    solver: &{solver},
+    generator: None,
+    visualizer: None,
+};
+"#;
+
+const TESTS_TEMPLATE: &str = r#"use test_case::test_case;
+
+use crate::{module}::{parse_input, solve_part_one, solve_part_two};
+
+static EXAMPLE_INPUT: &str = "";
+
+#[test_case(EXAMPLE_INPUT, 0; "example")]
+#[ignore = "The example input and its expected answer are not filled in yet"]
+fn test_part_one(input: &str, expected: usize) {
+    let input = parse_input(input).expect("The input to be correctly parsed");
+    assert_eq!(
+        solve_part_one(&input).expect("Part one to be solved"),
+        expected
+    );
+}
+
+#[test_case(EXAMPLE_INPUT, 0; "example")]
+#[ignore = "The example input and its expected answer are not filled in yet"]
+fn test_part_two(input: &str, expected: usize) {
+    let input = parse_input(input).expect("The input to be correctly parsed");
+    assert_eq!(
+        solve_part_two(&input).expect("Part two to be solved"),
+        expected
+    );
+}
+"#;
+
+/// Name of the module of a day, e.g third_day
+pub fn module_name(number: u8) -> Option<String> {
+    let ordinal = ORDINALS.get(usize::from(number).checked_sub(1)?)?;
+    Some(format!("{}_day", ordinal))
+}
+
+/// Name of the solution type of a day, e.g ThirdDay or TwentyFirstDay
+pub fn solver_name(module_name: &str) -> String {
+    module_name
+        .split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            characters
+                .next()
+                .map(|first| first.to_uppercase().chain(characters).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn day_number(module_name: &str) -> Option<u8> {
+    let ordinal = module_name.strip_suffix("_day")?;
+    let index = ORDINALS.iter().position(|&name| name == ordinal)?;
+    u8::try_from(index + 1).ok()
+}
+
+pub fn generate_module(number: u8, title: &str) -> Result<String> {
+    let module = module_name(number).ok_or_else(|| anyhow!("Invalid day {}", number))?;
+    Ok(MODULE_TEMPLATE
+        .replace("{number}", &number.to_string())
+        .replace("{solver}", &solver_name(&module))
+        .replace("{title_literal}", &format!("{:?}", title))
+        .replace("{title}", title))
+}
+
+pub fn generate_tests(number: u8) -> Result<String> {
+    let module = module_name(number).ok_or_else(|| anyhow!("Invalid day {}", number))?;
+    Ok(TESTS_TEMPLATE.replace("{module}", &module))
+}
+
+/// Declare the module in lib.rs, keeping the module declarations sorted
+pub fn register_module(lib_source: &str, module: &str) -> Result<String> {
+    let declaration = format!("pub mod {};", module);
+    let lines = lib_source.lines().collect::<Vec<&str>>();
+    if lines.contains(&declaration.as_str()) {
+        bail!("The module {} is already declared", module)
+    }
+    let declarations = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod "))
+        .collect::<Vec<_>>();
+    let index = declarations
+        .iter()
+        .find(|(_, line)| **line > declaration.as_str())
+        .map(|(index, _)| *index)
+        .or_else(|| declarations.last().map(|(index, _)| index + 1))
+        .unwrap_or(lines.len());
+    let mut output = lines;
+    output.insert(index, &declaration);
+    Ok(output.join("\n") + "\n")
+}
+
+/// Add the day to the registered days, before the first day that comes after it
+pub fn register_day(registry_source: &str, module: &str) -> Result<String> {
+    let number = day_number(module).ok_or_else(|| anyhow!("Invalid day module {}", module))?;
+    let lines = registry_source.lines().collect::<Vec<&str>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))
+        .ok_or_else(|| anyhow!("The DAYS list was not found in the registry"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| anyhow!("The end of the DAYS list was not found in the registry"))?;
+    let registered_numbers = lines[start + 1..end]
+        .iter()
+        .map(|line| {
+            line.trim()
+                .strip_prefix("crate::")
+                .and_then(|entry| entry.strip_suffix("::DAY,"))
+                .and_then(day_number)
+                .ok_or_else(|| anyhow!("Unexpected entry in the DAYS list: {}", line.trim()))
+        })
+        .collect::<Result<Vec<u8>>>()?;
+    if registered_numbers.contains(&number) {
+        bail!("Day {} is already registered", number)
+    }
+    let index = registered_numbers
+        .iter()
+        .position(|&registered| registered > number)
+        .map_or(end, |position| start + 1 + position);
+    let entry = format!("    crate::{}::DAY,", module);
+    let mut output = lines;
+    output.insert(index, &entry);
+    Ok(output.join("\n") + "\n")
+}
+
+fn read_source(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))
+}
+
+fn write_source(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// Create the module of a new day in the source directory and register it,
+/// returning the directory of the module
+pub fn create_day(source_directory: &Path, number: u8, title: &str) -> Result<PathBuf> {
+    let module = module_name(number).ok_or_else(|| anyhow!("Invalid day {}", number))?;
+    let module_directory = source_directory.join(&module);
+    if module_directory.exists() {
+        bail!("{} already exists", module_directory.display())
+    }
+    let lib_path = source_directory.join("lib.rs");
+    let registry_path = source_directory.join("registry.rs");
+    // Everything is generated before writing anything, so a failure leaves the sources untouched
+    let lib_source = register_module(&read_source(&lib_path)?, &module)?;
+    let registry_source = register_day(&read_source(&registry_path)?, &module)?;
+    let module_source = generate_module(number, title)?;
+    let tests_source = generate_tests(number)?;
+    fs::create_dir(&module_directory)
+        .with_context(|| format!("Could not create {}", module_directory.display()))?;
+    write_source(&module_directory.join("mod.rs"), &module_source)?;
+    write_source(&module_directory.join("tests.rs"), &tests_source)?;
+    write_source(&lib_path, &lib_source)?;
+    write_source(&registry_path, &registry_source)?;
+    Ok(module_directory)
+}
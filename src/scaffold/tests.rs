@@ -0,0 +1,133 @@
+use std::{env::temp_dir, fs};
+
+use test_case::test_case;
+
+use crate::scaffold::{
+    create_day, generate_module, generate_tests, module_name, register_day, register_module,
+    solver_name,
+};
+
+static LIB_SOURCE: &str = "//! Crate documentation
+
+pub mod first_day;
+pub mod registry;
+pub mod third_day;
+";
+
+static REGISTRY_SOURCE: &str = "use crate::solution::DaySolver;
+
+pub static DAYS: &[Day] = &[
+    crate::first_day::DAY,
+    crate::third_day::DAY,
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+";
+
+#[test_case(1, Some("first_day"); "first day")]
+#[test_case(8, Some("eight_day"); "existing eighth day module")]
+#[test_case(21, Some("twenty_first_day"); "compound ordinal")]
+#[test_case(0, None; "day zero")]
+#[test_case(26, None; "after christmas")]
+fn test_module_name(number: u8, expected: Option<&str>) {
+    assert_eq!(module_name(number).as_deref(), expected)
+}
+
+#[test_case("third_day", "ThirdDay")]
+#[test_case("eight_day", "EightDay")]
+#[test_case("twenty_first_day", "TwentyFirstDay")]
+fn test_solver_name(module: &str, expected: &str) {
+    assert_eq!(solver_name(module), expected)
+}
+
+#[test]
+fn test_generated_module_is_registered_with_its_title() {
+    let module = generate_module(12, "Some \"quoted\" title").expect("The module to be generated");
+    assert!(module.starts_with("//! Day 12: Some \"quoted\" title.\n"));
+    assert!(module.contains("pub struct TwelfthDay;"));
+    assert!(module.contains("    number: 12,\n    title: \"Some \\\"quoted\\\" title\",\n"));
+    assert!(module.contains("    solver: &TwelfthDay,"));
+}
+
+#[test]
+fn test_generated_tests_use_the_module() {
+    let tests = generate_tests(12).expect("The tests to be generated");
+    assert!(
+        tests.contains("use crate::twelfth_day::{parse_input, solve_part_one, solve_part_two};")
+    );
+    assert!(tests.contains("#[test_case(EXAMPLE_INPUT, 0; \"example\")]"));
+}
+
+#[test]
+fn test_module_declaration_is_sorted() {
+    let source = register_module(LIB_SOURCE, "second_day").expect("The module to be declared");
+    assert_eq!(
+        source,
+        "//! Crate documentation
+
+pub mod first_day;
+pub mod registry;
+pub mod second_day;
+pub mod third_day;
+"
+    );
+}
+
+#[test]
+fn test_module_cannot_be_declared_twice() {
+    assert!(register_module(LIB_SOURCE, "first_day").is_err())
+}
+
+#[test]
+fn test_day_is_registered_in_order() {
+    let source = register_day(REGISTRY_SOURCE, "second_day").expect("The day to be registered");
+    assert!(source.contains(
+        "    crate::first_day::DAY,
+    crate::second_day::DAY,
+    crate::third_day::DAY,
+];"
+    ));
+}
+
+#[test]
+fn test_last_day_is_registered_at_the_end() {
+    let source = register_day(REGISTRY_SOURCE, "twelfth_day").expect("The day to be registered");
+    assert!(source.contains(
+        "    crate::third_day::DAY,
+    crate::twelfth_day::DAY,
+];"
+    ));
+}
+
+#[test]
+fn test_day_cannot_be_registered_twice() {
+    assert!(register_day(REGISTRY_SOURCE, "third_day").is_err())
+}
+
+#[test]
+fn test_create_day() {
+    let source_directory = temp_dir().join("advent_of_code_2025_scaffold_test");
+    let _ = fs::remove_dir_all(&source_directory);
+    fs::create_dir_all(&source_directory).expect("The source directory to be created");
+    fs::write(source_directory.join("lib.rs"), LIB_SOURCE).expect("lib.rs to be written");
+    fs::write(source_directory.join("registry.rs"), REGISTRY_SOURCE)
+        .expect("registry.rs to be written");
+
+    let module_directory =
+        create_day(&source_directory, 2, "Gift Shop").expect("The day to be created");
+
+    assert_eq!(module_directory, source_directory.join("second_day"));
+    assert!(module_directory.join("mod.rs").is_file());
+    assert!(module_directory.join("tests.rs").is_file());
+    let lib_source =
+        fs::read_to_string(source_directory.join("lib.rs")).expect("lib.rs to be read");
+    assert!(lib_source.contains("pub mod second_day;"));
+    let registry_source =
+        fs::read_to_string(source_directory.join("registry.rs")).expect("registry.rs to be read");
+    assert!(registry_source.contains("crate::second_day::DAY,"));
+    // The day now exists, so it cannot be created again
+    assert!(create_day(&source_directory, 2, "Gift Shop").is_err());
+    fs::remove_dir_all(&source_directory).expect("The source directory to be removed");
+}
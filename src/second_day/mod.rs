@@ -1,11 +1,17 @@
+//! Day 2: Gift Shop, finding the invalid product ids made of repeated digits.
+
 use anyhow::Result;
 use itertools::Itertools;
-use std::{fs, iter::successors, num::ParseIntError};
+use rand::RngExt;
+use std::{iter::successors, num::ParseIntError};
 use thiserror::Error;
 
+use crate::{generator::InputRng, parsing::LineError, registry::Day, solution::Solution};
+
 #[cfg(test)]
 mod tests;
 
+/// Inclusive range of product ids, written as `11-22` in the input.
 #[derive(Debug)]
 pub struct InputIdRange {
     pub start: usize,
@@ -18,18 +24,22 @@ pub enum InvalidInputError {
     StartAndEndNotParsed(String),
     #[error("Could not parse int from {0}")]
     InvalidBoundary(#[from] ParseIntError),
+    #[error("Range starts at {start} after its end {end}")]
+    ReversedRange { start: usize, end: usize },
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
 impl InputIdRange {
+    /// Parse a single range, e.g. `11-22`.
     pub fn new(input_string: &str) -> Result<Self, InvalidInputError> {
         let split_input = input_string.trim().split("-").collect::<Vec<&str>>();
         if let [start, end] = split_input[..] {
-            Ok(InputIdRange {
-                start: start.parse::<usize>()?,
-                end: end.parse::<usize>()?,
-            })
+            let (start, end) = (start.parse::<usize>()?, end.parse::<usize>()?);
+            if start > end {
+                return Err(InvalidInputError::ReversedRange { start, end });
+            }
+            Ok(InputIdRange { start, end })
         } else {
             Err(InvalidInputError::StartAndEndNotParsed(
                 input_string.to_string(),
@@ -43,7 +53,8 @@ impl InputIdRange {
 // - For a number of length 2n, be a multiple of 10^n + 1 (e.g 1212 is 12*101, 134134 is 134 * 1001)
 // Based on this, we try to solve the challenge without ever converting the value to string
 
-fn number_is_silly(number: usize) -> bool {
+/// Whether the number is a sequence of digits repeated twice, e.g. 1212.
+pub fn number_is_silly(number: usize) -> bool {
     let number_length = successors(Some(number), |&n| (n >= 10).then(|| n / 10)).count() as u32;
     if number_length % 2 != 0 {
         false
@@ -54,9 +65,13 @@ fn number_is_silly(number: usize) -> bool {
     }
 }
 
-fn get_silly_numbers(input_range: &InputIdRange, silly_detector: fn(usize) -> bool) -> Vec<usize> {
+/// Numbers of the range matching the silly detector.
+pub fn get_silly_numbers(
+    input_range: &InputIdRange,
+    silly_detector: fn(usize) -> bool,
+) -> Vec<usize> {
     let mut output = Vec::new();
-    for i in input_range.start..input_range.end {
+    for i in input_range.start..=input_range.end {
         if silly_detector(i) {
             output.push(i);
         }
@@ -69,7 +84,8 @@ fn get_silly_numbers(input_range: &InputIdRange, silly_detector: fn(usize) -> bo
 // - If the number is repeated an even number of times (or if the number is 1 char long): our first use case already handles that.
 // - If the number is repeated an odd number of times (e.g 121212) 120000 + 1200 + 12 = 12*(10000 + 100 + 1) = 12 * 10101
 // 123123123 = 123 * 1001001
-fn number_is_silly_part_two(number: usize) -> bool {
+/// Whether the number is a sequence of digits repeated at least twice, e.g. 121212.
+pub fn number_is_silly_part_two(number: usize) -> bool {
     let number_length = successors(Some(number), |&n| (n >= 10).then(|| n / 10)).count() as u32;
     if number_length == 1 {
         false
@@ -93,25 +109,62 @@ fn number_is_silly_part_two(number: usize) -> bool {
     }
 }
 
-pub fn main() {
-    let input_ranges = fs::read_to_string("data/day_2.txt")
-        .expect("The input to be correctly read")
-        .split(",")
-        .map(|row| InputIdRange::new(row).expect("The input to be correctly parsed"))
-        .collect::<Vec<InputIdRange>>();
-
-    let first_silly_number_sum: usize = input_ranges
-        .iter()
-        .map(|input_range| get_silly_numbers(input_range, number_is_silly))
-        .flatten()
-        .sum();
-    dbg!(first_silly_number_sum);
-
-    let second_silly_number_sum = input_ranges
-        .iter()
-        .map(|input_range| get_silly_numbers(input_range, number_is_silly_part_two))
-        .flatten()
-        .unique()
-        .sum::<usize>();
-    dbg!(second_silly_number_sum);
+/// Random ranges of ids of up to 10 digits on a single line, the size is the number of ranges.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let n_digits = rng.random_range(1..=10);
+            let start = rng.random_range(10usize.pow(n_digits - 1)..10usize.pow(n_digits));
+            // The ranges stay small enough for their ids to be checked one by one
+            let end = start + rng.random_range(0..10_000);
+            format!("{}-{}", start, end)
+        })
+        .collect::<Vec<String>>()
+        .join(",")
 }
+
+/// Both parts sum the silly ids of the ranges, with a looser definition in part two.
+pub struct SecondDay;
+
+impl Solution for SecondDay {
+    type Input = Vec<InputIdRange>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // The ranges are comma separated, the offending range is reported rather than the whole line
+        let mut input_ranges = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            for range in line.split(",") {
+                input_ranges.push(
+                    InputIdRange::new(range)
+                        .map_err(|error| LineError::new(index, range, error))?,
+                );
+            }
+        }
+        Ok(input_ranges)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(input
+            .iter()
+            .flat_map(|input_range| get_silly_numbers(input_range, number_is_silly))
+            .sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        Ok(input
+            .iter()
+            .flat_map(|input_range| get_silly_numbers(input_range, number_is_silly_part_two))
+            .unique()
+            .sum())
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 2,
+    title: "Gift Shop",
+    solver: &SecondDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
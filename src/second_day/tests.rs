@@ -1,10 +1,25 @@
-use crate::second_day::number_is_silly_part_two;
+use proptest::prelude::*;
+use test_case::test_case;
+
+use crate::second_day::{
+    InputIdRange, get_silly_numbers, number_is_silly, number_is_silly_part_two,
+};
 
 #[test]
 fn test_part2_long_silly_number() {
     assert!(number_is_silly_part_two(1212121212))
 }
 
+#[test_case(11, 22, vec![11, 22]; "range ending on a silly id")]
+#[test_case(95, 115, vec![99]; "range ending after a silly id")]
+#[test_case(1188511880, 1188511885, vec![1188511885]; "long range ending on a silly id")]
+fn test_silly_numbers_of_range(start: usize, end: usize, expected: Vec<usize>) {
+    assert_eq!(
+        get_silly_numbers(&InputIdRange { start, end }, number_is_silly),
+        expected
+    )
+}
+
 #[test]
 fn test_part2_long_unsilly_number() {
     assert!(!number_is_silly_part_two(1231231231))
@@ -34,3 +49,47 @@ fn test_part2_length_10_silly_number() {
 fn test_part2_length_6_silly_number() {
     assert!(number_is_silly_part_two(121212))
 }
+
+// Reference detector comparing the digits with a pattern repeated n_repetitions times
+fn digits_are_repeated(number: usize, n_repetitions: impl Fn(usize) -> bool) -> bool {
+    let digits = number.to_string();
+    (1..digits.len()).any(|pattern_length| {
+        digits.len().is_multiple_of(pattern_length)
+            && n_repetitions(digits.len() / pattern_length)
+            && digits == digits[..pattern_length].repeat(digits.len() / pattern_length)
+    })
+}
+
+proptest! {
+    // The ids of the input have at most 11 digits
+    #[test]
+    fn test_silly_number_matches_repeated_digits(number in 0..100_000_000_000usize) {
+        prop_assert_eq!(
+            number_is_silly(number),
+            digits_are_repeated(number, |n_repetitions| n_repetitions == 2)
+        );
+        prop_assert_eq!(
+            number_is_silly_part_two(number),
+            digits_are_repeated(number, |n_repetitions| n_repetitions >= 2)
+        );
+    }
+
+    // Silly numbers are rare among random numbers, so they are also built from their pattern
+    #[test]
+    fn test_repeated_pattern_is_silly(
+        (pattern, n_repetitions) in (1..=5u32).prop_flat_map(|pattern_length| {
+            (10usize.pow(pattern_length - 1)..10usize.pow(pattern_length), 2..=11 / pattern_length)
+        })
+    ) {
+        let number = pattern
+            .to_string()
+            .repeat(n_repetitions as usize)
+            .parse()
+            .expect("The repeated pattern to be a number");
+        prop_assert_eq!(
+            number_is_silly(number),
+            digits_are_repeated(number, |n_repetitions| n_repetitions == 2)
+        );
+        prop_assert!(number_is_silly_part_two(number));
+    }
+}
@@ -1,18 +1,42 @@
-use std::{cmp::Ordering, collections::HashSet, fmt::Display, fs, iter::zip};
+//! Day 7: Laboratories, following tachyon beams through a manifold of splitters.
 
+use std::{cmp::Ordering, collections::HashSet, fmt::Display, iter::zip};
+
+use anyhow::Result;
 use itertools::Itertools;
+use rand::RngExt;
+use thiserror::Error;
+
+use crate::{
+    generator::InputRng,
+    parsing::LineError,
+    registry::Day,
+    solution::{Answer, Solution},
+    visualization::{Color, Picture, Shape},
+};
 
 #[cfg(test)]
 mod tests;
 
-struct TachyonManifold {
-    starting_position: usize,
-    splitter_lines: Vec<HashSet<usize>>,
+#[derive(Debug, Error)]
+pub enum ManifoldParsingError {
+    #[error("The manifold is empty")]
+    EmptyManifold,
+    #[error("The first line does not contain a starting point S")]
+    MissingStartingPoint,
 }
 
+/// The manifold, with the column where the beam starts and the columns of the splitters
+/// of each line containing at least one splitter.
 #[derive(Debug)]
-struct TachyonManifoldResult {
-    n_splits: usize,
+pub struct TachyonManifold {
+    pub starting_position: usize,
+    pub splitter_lines: Vec<HashSet<usize>>,
+}
+
+#[derive(Debug)]
+pub struct TachyonManifoldResult {
+    pub n_splits: usize,
 }
 
 impl Display for TachyonManifoldResult {
@@ -22,8 +46,8 @@ impl Display for TachyonManifoldResult {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct QuanticTachyonManifoldResult {
-    n_timelines: usize,
+pub struct QuanticTachyonManifoldResult {
+    pub n_timelines: usize,
 }
 
 impl Display for QuanticTachyonManifoldResult {
@@ -32,8 +56,43 @@ impl Display for QuanticTachyonManifoldResult {
     }
 }
 
+impl From<TachyonManifoldResult> for Answer {
+    fn from(value: TachyonManifoldResult) -> Self {
+        Answer::Number(value.n_splits)
+    }
+}
+
+impl From<QuanticTachyonManifoldResult> for Answer {
+    fn from(value: QuanticTachyonManifoldResult) -> Self {
+        Answer::Number(value.n_timelines)
+    }
+}
+
 impl TachyonManifold {
-    fn run(&self) -> TachyonManifoldResult {
+    /// Parse the diagram, where the start is marked with `S` and the splitters with `^`.
+    pub fn new(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+        let first_line = lines.next().ok_or(ManifoldParsingError::EmptyManifold)?;
+        let starting_position = first_line.chars().position(|c| c == 'S').ok_or_else(|| {
+            LineError::new(0, first_line, ManifoldParsingError::MissingStartingPoint)
+        })?;
+        let splitter_lines = lines
+            .filter(|&line| line.contains("^"))
+            .map(|line| {
+                line.chars()
+                    .enumerate()
+                    .filter_map(|(index, c)| if c == '^' { Some(index) } else { None })
+                    .collect::<HashSet<usize>>()
+            })
+            .collect::<Vec<HashSet<usize>>>();
+        Ok(TachyonManifold {
+            starting_position,
+            splitter_lines,
+        })
+    }
+
+    /// Count how many times the beam is split while going down the manifold.
+    pub fn run(&self) -> TachyonManifoldResult {
         let mut beam = TachyonBeams {
             current_position: HashSet::from([self.starting_position]),
             n_splits: 0,
@@ -45,7 +104,8 @@ impl TachyonManifold {
             n_splits: beam.n_splits,
         }
     }
-    fn run_quantic(&self) -> QuanticTachyonManifoldResult {
+    /// Count the timelines of a single particle taking both ways at each splitter.
+    pub fn run_quantic(&self) -> QuanticTachyonManifoldResult {
         let mut beams = vec![QuanticTachyonBeams {
             current_position: HashSet::from([self.starting_position]),
             n_previous_timelines: 1,
@@ -193,37 +253,115 @@ impl QuanticTachyonBeams {
     }
 }
 
-pub fn main() {
-    let input = fs::read_to_string("data/day_7.txt")
-        .expect("The input to be correctly read")
-        .lines()
-        .map(String::from)
-        .collect::<Vec<String>>();
-    let starting_position = input
-        .first()
-        .expect("The input to not be empty")
-        .chars()
-        .position(|c| c == 'S')
-        .expect("The first line to contain a starting point");
-    let splitter_lines = input[1..]
+/// Random manifold where the splitters are only placed where the beams can reach them, with an
+/// empty line between the lines of splitters. The size is the number of lines of splitters.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let start = size + 1;
+    let mut lines = vec![
+        (0..width)
+            .map(|x| if x == start { 'S' } else { '.' })
+            .collect::<String>(),
+    ];
+    for depth in 0..size {
+        lines.push(".".repeat(width));
+        // Like in the puzzle, the splitters of a line are `depth` positions away from the
+        // start at most, every other position
+        lines.push(
+            (0..width)
+                .map(|x| {
+                    let offset = x.abs_diff(start);
+                    let reachable = offset <= depth && (offset + depth) % 2 == 0;
+                    if reachable && rng.random_bool(0.6) {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect(),
+        );
+    }
+    lines.join("\n")
+}
+
+/// Picture of the beams going down the manifold, the splitters reached by a beam are drawn in
+/// yellow and the other ones in gray.
+pub fn visualize(input: &str) -> Result<Vec<Picture>> {
+    let manifold = TachyonManifold::new(input)?;
+    let lines = input.lines().collect::<Vec<&str>>();
+    let width = lines
         .iter()
-        .filter(|&line| line.contains("^"))
-        .map(|line| {
-            line.chars()
-                .enumerate()
-                .filter_map(|(index, c)| if c == '^' { Some(index) } else { None })
-                .collect::<HashSet<usize>>()
-        })
-        .collect::<Vec<HashSet<usize>>>();
-    let manifold = TachyonManifold {
-        starting_position,
-        splitter_lines,
-    };
-    let result = manifold.run();
-    println!("The manifold has the following stats {}", result);
-    let result_quantic = manifold.run_quantic();
-    println!(
-        "The manifold has the following quantic stats {}",
-        result_quantic
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut picture = Picture::new(
+        format!("{} splits", manifold.run().n_splits),
+        width,
+        lines.len(),
     );
+    picture.push(Shape::Cell {
+        at: (manifold.starting_position, 0),
+        glyph: 'S',
+        color: Color::Green,
+    });
+    let mut beam = TachyonBeams {
+        current_position: HashSet::from([manifold.starting_position]),
+        n_splits: 0,
+    };
+    for (y, line) in lines.iter().enumerate().skip(1) {
+        let splitters = line
+            .chars()
+            .positions(|c| c == '^')
+            .collect::<HashSet<usize>>();
+        for &x in &splitters {
+            let color = if beam.current_position.contains(&x) {
+                Color::Yellow
+            } else {
+                Color::Gray
+            };
+            picture.push(Shape::Cell {
+                at: (x, y),
+                glyph: '^',
+                color,
+            });
+        }
+        beam = beam.advance(&splitters);
+        for &x in beam.current_position.difference(&splitters) {
+            picture.push(Shape::Cell {
+                at: (x, y),
+                glyph: '|',
+                color: Color::Cyan,
+            });
+        }
+    }
+    Ok(vec![picture])
 }
+
+/// Part one counts the splits of the beam, part two the quantic timelines.
+pub struct SeventhDay;
+
+impl Solution for SeventhDay {
+    type Input = TachyonManifold;
+    type FirstAnswer = TachyonManifoldResult;
+    type SecondAnswer = QuanticTachyonManifoldResult;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        TachyonManifold::new(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<TachyonManifoldResult> {
+        Ok(input.run())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<QuanticTachyonManifoldResult> {
+        Ok(input.run_quantic())
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 7,
+    title: "Laboratories",
+    solver: &SeventhDay,
+    generator: Some(generate_input),
+    visualizer: Some(visualize),
+};
@@ -1,7 +1,14 @@
 use std::collections::HashSet;
 
-use crate::seventh_day::{
-    QuanticTachyonBeams, QuanticTachyonManifoldResult, TachyonBeams, TachyonManifold,
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+use crate::{
+    generator::InputRng,
+    seventh_day::{
+        QuanticTachyonBeams, QuanticTachyonManifoldResult, TachyonBeams, TachyonManifold,
+        generate_input,
+    },
 };
 
 #[test]
@@ -118,3 +125,46 @@ fn test_quantic_manifold_run() {
     let result = manifold.run_quantic();
     assert_eq!(result, QuanticTachyonManifoldResult { n_timelines: 40 })
 }
+
+// Reference number of splits and of timelines, following the number of timelines of every
+// column line by line
+fn count_splits_and_timelines(input: &str) -> (usize, usize) {
+    let mut lines = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<char>>());
+    let first_line = lines.next().expect("The manifold to have a first line");
+    let mut timelines = first_line
+        .iter()
+        .map(|&element| usize::from(element == 'S'))
+        .collect::<Vec<usize>>();
+    let mut n_splits = 0;
+    for line in lines {
+        let mut next_timelines = vec![0; timelines.len()];
+        for (column, &n_timelines) in timelines.iter().enumerate() {
+            if n_timelines == 0 {
+                continue;
+            }
+            if line[column] == '^' {
+                n_splits += 1;
+                next_timelines[column - 1] += n_timelines;
+                next_timelines[column + 1] += n_timelines;
+            } else {
+                next_timelines[column] += n_timelines;
+            }
+        }
+        timelines = next_timelines;
+    }
+    (n_splits, timelines.iter().sum())
+}
+
+proptest! {
+    #[test]
+    fn test_manifold_matches_timelines_by_column(seed in any::<u64>(), size in 1..30usize) {
+        let input = generate_input(&mut InputRng::seed_from_u64(seed), size);
+        let manifold = TachyonManifold::new(&input).expect("The manifold to be parsed");
+        prop_assert_eq!(
+            (manifold.run().n_splits, manifold.run_quantic().n_timelines),
+            count_splits_and_timelines(&input)
+        );
+    }
+}
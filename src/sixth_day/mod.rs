@@ -1,12 +1,17 @@
+//! Day 6: Trash Compactor, solving the math worksheet of the cephalopods.
+
 use anyhow::Result;
-use std::{fs, num::ParseIntError};
+use rand::RngExt;
+use std::{iter::zip, num::ParseIntError};
 use thiserror::Error;
 
+use crate::{generator::InputRng, registry::Day, solution::Solution};
+
 #[cfg(test)]
 mod tests;
 
 #[derive(Debug)]
-enum Operator {
+pub enum Operator {
     Addition,
     Multiplication,
 }
@@ -42,19 +47,20 @@ fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
 }
 
 #[derive(Debug, Error)]
-enum OperatorParsingError {
+pub enum OperatorParsingError {
     #[error("Expected + or *, got {0}")]
     InvalidOperatorCharacter(String),
 }
 
+/// A problem of the worksheet, where every figure is added or multiplied.
 #[derive(Debug)]
-struct SquidProblem {
-    figures: Vec<usize>,
-    operator: Operator,
+pub struct SquidProblem {
+    pub figures: Vec<usize>,
+    pub operator: Operator,
 }
 
 #[derive(Debug, Error)]
-enum SquidProblemParsingError {
+pub enum SquidProblemParsingError {
     #[error("Invalid operator instruction")]
     InvalidOperatorInstruction(#[from] OperatorParsingError),
     #[error("Invalid figures instructions")]
@@ -64,7 +70,7 @@ enum SquidProblemParsingError {
 }
 
 impl SquidProblem {
-    fn solve(&self) -> usize {
+    pub fn solve(&self) -> usize {
         match self.operator {
             Operator::Addition => self.figures.iter().sum(),
             Operator::Multiplication => self.figures.iter().product(),
@@ -84,43 +90,55 @@ fn get_split_columns_index(operator_line: &String) -> Vec<usize> {
     output
 }
 
+// The indexes are character indexes of the operator line, a line shorter than the operator
+// line gets empty columns instead of being sliced out of bounds
 fn split_problems_line(line: &str, indexes_to_split: &Vec<usize>) -> Vec<String> {
+    let characters = line.chars().collect::<Vec<char>>();
+    let column = |start_index: usize, end_index: usize| {
+        characters[start_index.min(characters.len())..end_index.min(characters.len())]
+            .iter()
+            .collect::<String>()
+    };
     let mut output = Vec::new();
     let mut start_index = 0;
     for &end_index in indexes_to_split.iter() {
-        output.push(line[start_index..end_index].to_string());
+        output.push(column(start_index, end_index));
         start_index = end_index + 1;
     }
     // Push the last column
-    output.push(line[start_index..line.len()].to_string());
+    output.push(column(start_index, characters.len()));
     output
 }
 
-//Parse the problems as defined in the first part of the problem
-fn parse_first(instructions: &Vec<String>) -> Result<SquidProblem, SquidProblemParsingError> {
+/// Parse a problem whose figures are written in rows, as in the first part.
+pub fn parse_first(instructions: &Vec<String>) -> Result<SquidProblem, SquidProblemParsingError> {
     if instructions.is_empty() {
         Err(SquidProblemParsingError::EmptyInstruction)
     } else {
         let figures = instructions[..instructions.len() - 1]
             .iter()
-            .map(|figure| {
-                figure
-                    .trim()
-                    .parse::<usize>()
-                    .expect("The first instructions to be only figures")
-            })
-            .collect();
-        let operator = Operator::try_from(instructions.last().unwrap().as_str().trim())?;
+            .map(|figure| figure.trim().parse::<usize>())
+            .collect::<Result<Vec<usize>, ParseIntError>>()?;
+        let operator = parse_operator(instructions)?;
         Ok(SquidProblem { figures, operator })
     }
 }
 
-fn parse_second(instructions: &Vec<String>) -> Result<SquidProblem, SquidProblemParsingError> {
+fn parse_operator(instructions: &[String]) -> Result<Operator, SquidProblemParsingError> {
+    let operator_instruction = instructions
+        .last()
+        .ok_or(SquidProblemParsingError::EmptyInstruction)?;
+    Ok(Operator::try_from(operator_instruction.trim())?)
+}
+
+/// Parse a problem whose figures are written in columns, as in the second part.
+pub fn parse_second(instructions: &Vec<String>) -> Result<SquidProblem, SquidProblemParsingError> {
+    let operator = parse_operator(instructions)?;
     let max_significant_numbers = instructions[..instructions.len() - 1]
         .iter()
-        .map(|figure| figure.len())
+        .map(|figure| figure.chars().count())
         .max()
-        .expect("The instructions to not be empty");
+        .ok_or(SquidProblemParsingError::EmptyInstruction)?;
     let padded_instructions = instructions[..instructions.len() - 1]
         .iter()
         .map(|figure| {
@@ -132,45 +150,119 @@ fn parse_second(instructions: &Vec<String>) -> Result<SquidProblem, SquidProblem
     let instructions_in_squid_language = transpose(padded_instructions);
     let figures = instructions_in_squid_language
         .into_iter()
-        .map(|row| {
-            row.into_iter()
-                .collect::<String>()
-                .trim()
-                .parse::<usize>()
-                .expect("The instructions to be correctly parsed")
-        })
-        .collect();
-
-    let operator = Operator::try_from(instructions.last().unwrap().as_str().trim())?;
+        .map(|row| row.into_iter().collect::<String>().trim().parse::<usize>())
+        .collect::<Result<Vec<usize>, ParseIntError>>()?;
     Ok(SquidProblem { figures, operator })
 }
 
-pub fn main() {
-    let input_lines = fs::read_to_string("data/day_6.txt")
-        .expect("The input file to be correctly read")
-        .lines()
-        .map(String::from)
-        .collect::<Vec<String>>();
+/// Split the worksheet into the instructions of each problem, the rows of a problem
+/// keep their padding as the alignment of the figures matters in the second part.
+pub fn parse_worksheet(input: &str) -> Result<Vec<Vec<String>>> {
+    let input_lines = input.lines().map(String::from).collect::<Vec<String>>();
     let split_indexes = get_split_columns_index(
         input_lines
             .last()
-            .expect("The instructions to not be empty"),
+            .ok_or(SquidProblemParsingError::EmptyInstruction)?,
     );
     let input_lines = input_lines
         .iter()
         .map(|line| split_problems_line(line, &split_indexes))
         .collect::<Vec<Vec<String>>>();
-    let input = transpose(input_lines);
-    let first_problems = input
+    Ok(transpose(input_lines))
+}
+
+// The problems are written in columns, so the offending problem is reported instead of a line
+#[derive(Debug, Error)]
+#[error("Invalid problem {problem_number}: {instructions:?}")]
+pub struct WorksheetProblemError {
+    pub problem_number: usize,
+    pub instructions: Vec<String>,
+    #[source]
+    pub error: SquidProblemParsingError,
+}
+
+/// Sum of the solutions of every problem, parsed with the given parser.
+pub fn solve_problems(
+    worksheet: &[Vec<String>],
+    parser: fn(&Vec<String>) -> Result<SquidProblem, SquidProblemParsingError>,
+) -> Result<usize> {
+    Ok(worksheet
+        .iter()
+        .enumerate()
+        .map(|(index, instructions)| {
+            parser(instructions).map_err(|error| WorksheetProblemError {
+                problem_number: index + 1,
+                instructions: instructions.clone(),
+                error,
+            })
+        })
+        .collect::<Result<Vec<SquidProblem>, WorksheetProblemError>>()?
         .iter()
-        .map(|instructions| parse_first(instructions).expect("The problem to be correctly parsed"))
-        .collect::<Vec<SquidProblem>>();
-    let first_solved_problems: usize = first_problems.iter().map(|problem| problem.solve()).sum();
-    dbg!(first_solved_problems);
-    let second_problems = input
+        .map(|problem| problem.solve())
+        .sum())
+}
+
+/// Random worksheet of problems of 4 numbers of up to 4 digits, aligned to the left or to the
+/// right of their problem. The size is the number of problems.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    let mut lines = vec![Vec::new(); 5];
+    for _ in 0..size {
+        let width = rng.random_range(1..=4);
+        let left_aligned = rng.random_bool(0.5);
+        // One of the numbers has every digit, so no column of the problem is empty, and the
+        // numbers are sorted by length so the digits of a column are not separated by spaces
+        let mut lengths = (0..3)
+            .map(|_| rng.random_range(1..=width))
+            .chain([width])
+            .collect::<Vec<usize>>();
+        lengths.sort();
+        if rng.random_bool(0.5) {
+            lengths.reverse();
+        }
+        for (n_digits, line) in zip(lengths, lines[..4].iter_mut()) {
+            let number =
+                rng.random_range(10usize.pow(n_digits as u32 - 1)..10usize.pow(n_digits as u32));
+            line.push(if left_aligned {
+                format!("{:<width$}", number)
+            } else {
+                format!("{:>width$}", number)
+            });
+        }
+        let operator = if rng.random_bool(0.5) { '+' } else { '*' };
+        lines[4].push(format!("{:<width$}", operator));
+    }
+    lines
         .iter()
-        .map(|instructions| parse_second(instructions).expect("The problem to be correctly parsed"))
-        .collect::<Vec<SquidProblem>>();
-    let second_problems_solved: usize = second_problems.iter().map(|problem| problem.solve()).sum();
-    dbg!(second_problems_solved);
+        .map(|line| line.join(" "))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Both parts sum the solutions of the problems, reading the figures differently.
+pub struct SixthDay;
+
+impl Solution for SixthDay {
+    type Input = Vec<Vec<String>>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_worksheet(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        solve_problems(input, parse_first)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        solve_problems(input, parse_second)
+    }
 }
+
+pub const DAY: Day = Day {
+    number: 6,
+    title: "Trash Compactor",
+    solver: &SixthDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
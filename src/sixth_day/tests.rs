@@ -1,4 +1,12 @@
-use crate::sixth_day::{get_split_columns_index, split_problems_line};
+use itertools::Itertools;
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+use crate::{
+    generator::InputRng,
+    sixth_day::{SixthDay, generate_input, get_split_columns_index, split_problems_line},
+    solution::Solution,
+};
 
 #[test]
 fn test_get_split_index() {
@@ -13,3 +21,80 @@ fn test_split_by_index() {
     let output = split_problems_line(line, &split_indexes);
     assert_eq!(output, vec!["12", "134 ", "2", "23  "])
 }
+
+#[test]
+fn test_split_line_shorter_than_operators() {
+    let output = split_problems_line("12 13", &vec![2, 7, 9]);
+    assert_eq!(output, vec!["12", "13", "", ""])
+}
+
+#[test]
+fn test_split_line_with_multi_byte_characters() {
+    let output = split_problems_line("1é 3", &vec![2]);
+    assert_eq!(output, vec!["1é", "3"])
+}
+
+#[test]
+fn test_worksheet_with_multi_byte_figure_is_rejected() {
+    let worksheet = SixthDay::parse("12 3\n4é 5\n*  +").expect("The worksheet to be split");
+    assert!(SixthDay::part_one(&worksheet).is_err());
+    assert!(SixthDay::part_two(&worksheet).is_err());
+}
+
+// Reference solutions of the worksheet read as a grid of characters, the problems are
+// separated by columns of spaces, their figures are read by row in part one and by column
+// in part two
+fn solve_worksheet_grid(input: &str) -> (usize, usize) {
+    let grid = input
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect::<Vec<Vec<char>>>();
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |row: usize, column: usize| grid[row].get(column).copied().unwrap_or(' ');
+    let (figure_rows, operator_row) = (0..grid.len() - 1, grid.len() - 1);
+    let problems_columns = (0..width)
+        .chunk_by(|&column| (0..grid.len()).all(|row| cell(row, column) == ' '))
+        .into_iter()
+        .filter(|(is_separator, _)| !is_separator)
+        .map(|(_, columns)| columns.collect())
+        .collect::<Vec<Vec<usize>>>();
+
+    let solve = |figures: Vec<usize>, columns: &[usize]| {
+        if columns
+            .iter()
+            .any(|&column| cell(operator_row, column) == '*')
+        {
+            figures.iter().product::<usize>()
+        } else {
+            figures.iter().sum::<usize>()
+        }
+    };
+    let parse_figure = |digits: String| digits.trim().parse().expect("The figure to be a number");
+    let (mut by_row, mut by_column) = (0, 0);
+    for columns in problems_columns {
+        let row_figures = figure_rows
+            .clone()
+            .map(|row| parse_figure(columns.iter().map(|&column| cell(row, column)).collect()))
+            .collect();
+        by_row += solve(row_figures, &columns);
+        let column_figures = columns
+            .iter()
+            .map(|&column| parse_figure(figure_rows.clone().map(|row| cell(row, column)).collect()))
+            .collect();
+        by_column += solve(column_figures, &columns);
+    }
+    (by_row, by_column)
+}
+
+proptest! {
+    #[test]
+    fn test_solutions_match_worksheet_grid(seed in any::<u64>(), size in 1..30usize) {
+        let input = generate_input(&mut InputRng::seed_from_u64(seed), size);
+        let worksheet = SixthDay::parse(&input).expect("The worksheet to be parsed");
+        let solutions = (
+            SixthDay::part_one(&worksheet).expect("The first part to be solved"),
+            SixthDay::part_two(&worksheet).expect("The second part to be solved"),
+        );
+        prop_assert_eq!(solutions, solve_worksheet_grid(&input));
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    any::{Any, type_name},
+    fmt::Display,
+};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use tracing::info_span;
+
+/// Part(s) of a day to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    First,
+    Second,
+    Both,
+}
+
+impl Part {
+    pub fn from_number(part: Option<u8>) -> Self {
+        match part {
+            Some(1) => Part::First,
+            Some(2) => Part::Second,
+            _ => Part::Both,
+        }
+    }
+
+    pub fn includes_first(&self) -> bool {
+        matches!(self, Part::First | Part::Both)
+    }
+
+    pub fn includes_second(&self) -> bool {
+        matches!(self, Part::Second | Part::Both)
+    }
+}
+
+/// Answer of one part of a day, every typed answer of a solution must be convertible to it
+/// so the days can be handled uniformly by the runner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(usize),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Solution of a day: its input is parsed once, then shared by both parts.
+pub trait Solution {
+    type Input: Send + Sync + 'static;
+    type FirstAnswer: Into<Answer>;
+    type SecondAnswer: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::FirstAnswer>;
+    fn part_two(input: &Self::Input) -> Result<Self::SecondAnswer>;
+}
+
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// Object safe version of a Solution, where the parsed input and the answers are type erased.
+/// Each phase runs in a span named after it, with the solution as a field.
+pub trait DaySolver: Sync {
+    fn parse(&self, input: &str) -> Result<ParsedInput>;
+    fn part_one(&self, input: &ParsedInput) -> Result<Answer>;
+    fn part_two(&self, input: &ParsedInput) -> Result<Answer>;
+}
+
+fn downcast_input<S: Solution>(input: &ParsedInput) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("The parsed input does not belong to this solution"))
+}
+
+impl<S: Solution + Sync> DaySolver for S {
+    fn parse(&self, input: &str) -> Result<ParsedInput> {
+        let _span = info_span!("parse", solution = type_name::<S>()).entered();
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_one(&self, input: &ParsedInput) -> Result<Answer> {
+        let _span = info_span!("part_one", solution = type_name::<S>()).entered();
+        S::part_one(downcast_input::<S>(input)?).map(Into::into)
+    }
+
+    fn part_two(&self, input: &ParsedInput) -> Result<Answer> {
+        let _span = info_span!("part_two", solution = type_name::<S>()).entered();
+        S::part_two(downcast_input::<S>(input)?).map(Into::into)
+    }
+}
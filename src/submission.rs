@@ -0,0 +1,268 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{fetcher::USER_AGENT, solution::Answer};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_HISTORY_PATH: &str = "data/submissions.json";
+/// Minimal delay between two submissions, the website rejects answers submitted too quickly
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Verdict of the website on a submitted answer, read from the page it answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    TooSoon,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if response.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            }
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if response.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::TooSoon => write!(f, "submitted too soon, wait before submitting again"),
+            Verdict::AlreadySolved => write!(f, "this part is already solved"),
+            Verdict::Unknown => {
+                write!(f, "unknown, the response of the website was not understood")
+            }
+        }
+    }
+}
+
+/// Posts the answers to `<base_url>/day/<day>/answer`, authenticated by the session token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerSubmitter {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl AnswerSubmitter {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        AnswerSubmitter {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn answer_url(&self, day: u8) -> String {
+        format!("{}/day/{}/answer", self.base_url, day)
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) -> Result<Verdict> {
+        let url = self.answer_url(day);
+        let response = ureq::post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .with_context(|| format!("Could not submit the answer of day {} to {}", day, url))?;
+        Ok(Verdict::from_response(&response))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+/// Reason for not submitting an answer, so the website is only asked when it is useful.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SubmissionRefusal {
+    #[error("Day {day} part {part} is already solved, the answer was {answer}")]
+    AlreadySolved { day: u8, part: u8, answer: Answer },
+    #[error("{answer} was already submitted for day {day} part {part}: {verdict}")]
+    KnownWrongAnswer {
+        day: u8,
+        part: u8,
+        answer: Answer,
+        verdict: Verdict,
+    },
+    #[error("{answer} is not lower than {bound}, which was already too high")]
+    AboveTooHighAnswer { answer: usize, bound: usize },
+    #[error("{answer} is not higher than {bound}, which was already too low")]
+    BelowTooLowAnswer { answer: usize, bound: usize },
+    #[error("The last answer was submitted {elapsed}s ago, wait {remaining}s before submitting")]
+    Cooldown { elapsed: u64, remaining: u64 },
+}
+
+/// Every answer submitted so far, with the verdict of the website.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionHistory {
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionHistory {
+    /// A missing history file is an empty history
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(SubmissionHistory::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read the submission history {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid submission history {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Could not write the submission history {}", path.display()))
+    }
+
+    fn submissions_of(&self, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part)
+    }
+
+    /// Check the answer is worth submitting at the given time, in seconds since the unix epoch.
+    pub fn check(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+        now: u64,
+        cooldown: Duration,
+    ) -> Result<(), SubmissionRefusal> {
+        for submission in self.submissions_of(day, part) {
+            match (submission.verdict, &submission.answer, answer) {
+                (Verdict::Correct, _, _) => {
+                    return Err(SubmissionRefusal::AlreadySolved {
+                        day,
+                        part,
+                        answer: submission.answer.clone(),
+                    });
+                }
+                (verdict, submitted, _) if verdict.is_wrong() && submitted == answer => {
+                    return Err(SubmissionRefusal::KnownWrongAnswer {
+                        day,
+                        part,
+                        answer: answer.clone(),
+                        verdict,
+                    });
+                }
+                (Verdict::TooHigh, Answer::Number(bound), Answer::Number(answer))
+                    if answer >= bound =>
+                {
+                    return Err(SubmissionRefusal::AboveTooHighAnswer {
+                        answer: *answer,
+                        bound: *bound,
+                    });
+                }
+                (Verdict::TooLow, Answer::Number(bound), Answer::Number(answer))
+                    if answer <= bound =>
+                {
+                    return Err(SubmissionRefusal::BelowTooLowAnswer {
+                        answer: *answer,
+                        bound: *bound,
+                    });
+                }
+                _ => {}
+            }
+        }
+        // The rate limit of the website applies to every day
+        if let Some(last_submission) = self
+            .submissions
+            .iter()
+            .map(|submission| submission.submitted_at)
+            .max()
+        {
+            let elapsed = now.saturating_sub(last_submission);
+            if elapsed < cooldown.as_secs() {
+                return Err(SubmissionRefusal::Cooldown {
+                    elapsed,
+                    remaining: cooldown.as_secs() - elapsed,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: Answer, verdict: Verdict, now: u64) {
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer,
+            verdict,
+            submitted_at: now,
+        });
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Submit the answer unless the history shows it is not worth it, then record the verdict.
+pub fn submit_answer(
+    submitter: &AnswerSubmitter,
+    history_path: &Path,
+    day: u8,
+    part: u8,
+    answer: Answer,
+    cooldown: Duration,
+) -> Result<Verdict> {
+    let mut history = SubmissionHistory::load(history_path)?;
+    let submitted_at = now();
+    history.check(day, part, &answer, submitted_at, cooldown)?;
+    let verdict = submitter.submit(day, part, &answer)?;
+    history.record(day, part, answer, verdict, submitted_at);
+    history.save(history_path)?;
+    Ok(verdict)
+}
@@ -0,0 +1,151 @@
+use std::{env::temp_dir, fs, time::Duration};
+
+use test_case::test_case;
+
+use crate::{
+    fetcher::USER_AGENT,
+    solution::Answer,
+    submission::{AnswerSubmitter, SubmissionHistory, SubmissionRefusal, Verdict, submit_answer},
+    test_server::TestServer,
+};
+
+const COOLDOWN: Duration = Duration::from_secs(60);
+
+fn history(submissions: &[(u8, u8, usize, Verdict, u64)]) -> SubmissionHistory {
+    let mut history = SubmissionHistory::default();
+    for &(day, part, answer, verdict, submitted_at) in submissions {
+        history.record(day, part, Answer::Number(answer), verdict, submitted_at);
+    }
+    history
+}
+
+#[test_case("<article><p>That's the right answer! You are one gold star closer.</p></article>", Verdict::Correct; "correct")]
+#[test_case("<article><p>That's not the right answer; your answer is too high.</p></article>", Verdict::TooHigh; "too high")]
+#[test_case("<article><p>That's not the right answer; your answer is too low.</p></article>", Verdict::TooLow; "too low")]
+#[test_case("<article><p>That's not the right answer.</p></article>", Verdict::Incorrect; "incorrect")]
+#[test_case("<article><p>You gave an answer too recently; you have to wait.</p></article>", Verdict::TooSoon; "too soon")]
+#[test_case("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>", Verdict::AlreadySolved; "already solved")]
+#[test_case("<html>Maintenance</html>", Verdict::Unknown; "unknown")]
+fn test_verdict_from_response(response: &str, expected: Verdict) {
+    assert_eq!(Verdict::from_response(response), expected)
+}
+
+#[test]
+fn test_new_answer_can_be_submitted() {
+    let history = history(&[(1, 1, 42, Verdict::Incorrect, 0)]);
+    assert_eq!(
+        history.check(1, 1, &Answer::Number(43), 100, COOLDOWN),
+        Ok(())
+    )
+}
+
+#[test]
+fn test_known_wrong_answer_is_refused() {
+    let history = history(&[(1, 1, 42, Verdict::Incorrect, 0)]);
+    assert_eq!(
+        history.check(1, 1, &Answer::Number(42), 100, COOLDOWN),
+        Err(SubmissionRefusal::KnownWrongAnswer {
+            day: 1,
+            part: 1,
+            answer: Answer::Number(42),
+            verdict: Verdict::Incorrect
+        })
+    )
+}
+
+#[test]
+fn test_wrong_answer_of_another_part_is_not_refused() {
+    let history = history(&[(1, 1, 42, Verdict::Incorrect, 0)]);
+    assert!(
+        history
+            .check(1, 2, &Answer::Number(42), 100, COOLDOWN)
+            .is_ok()
+    )
+}
+
+#[test_case(50, Err(SubmissionRefusal::AboveTooHighAnswer { answer: 50, bound: 40 }); "above a too high answer")]
+#[test_case(10, Err(SubmissionRefusal::BelowTooLowAnswer { answer: 10, bound: 20 }); "below a too low answer")]
+#[test_case(30, Ok(()); "between the bounds")]
+fn test_answer_bounds(answer: usize, expected: Result<(), SubmissionRefusal>) {
+    let history = history(&[
+        (1, 1, 40, Verdict::TooHigh, 0),
+        (1, 1, 20, Verdict::TooLow, 0),
+    ]);
+    assert_eq!(
+        history.check(1, 1, &Answer::Number(answer), 100, COOLDOWN),
+        expected
+    )
+}
+
+#[test]
+fn test_solved_part_is_refused() {
+    let history = history(&[(3, 2, 42, Verdict::Correct, 0)]);
+    assert_eq!(
+        history.check(3, 2, &Answer::Number(43), 100, COOLDOWN),
+        Err(SubmissionRefusal::AlreadySolved {
+            day: 3,
+            part: 2,
+            answer: Answer::Number(42)
+        })
+    )
+}
+
+#[test]
+fn test_cooldown_applies_to_every_day() {
+    let history = history(&[(1, 1, 42, Verdict::Incorrect, 1000)]);
+    assert_eq!(
+        history.check(2, 1, &Answer::Number(7), 1045, COOLDOWN),
+        Err(SubmissionRefusal::Cooldown {
+            elapsed: 45,
+            remaining: 15
+        })
+    );
+    assert!(
+        history
+            .check(2, 1, &Answer::Number(7), 1060, COOLDOWN)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_submit_posts_the_answer_and_records_the_verdict() {
+    let history_path = temp_dir().join("advent_of_code_2025_submissions_test.json");
+    let _ = fs::remove_file(&history_path);
+    let server = TestServer::start(200, "<article><p>That's the right answer!</p></article>");
+    let submitter = AnswerSubmitter::new(&server.url, "secret");
+
+    let verdict = submit_answer(
+        &submitter,
+        &history_path,
+        5,
+        2,
+        Answer::Number(1234),
+        COOLDOWN,
+    )
+    .expect("The answer to be submitted");
+    // The part is solved, so the answer is not submitted again
+    let resubmission = submit_answer(
+        &submitter,
+        &history_path,
+        5,
+        2,
+        Answer::Number(1234),
+        Duration::ZERO,
+    );
+    let history = SubmissionHistory::load(&history_path);
+    fs::remove_file(&history_path).expect("The history to be removed");
+
+    assert_eq!(verdict, Verdict::Correct);
+    assert!(resubmission.is_err());
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/day/5/answer");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    assert_eq!(requests[0].body, "level=2&answer=1234");
+    let submissions = history.expect("The history to be saved").submissions;
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0].verdict, Verdict::Correct);
+    assert_eq!(submissions[0].answer, Answer::Number(1234));
+}
@@ -1,29 +1,45 @@
+//! Day 10: Factory, pressing the buttons of machines to configure their lights and joltages.
+
 use std::{
     cmp::min,
     collections::{HashMap, HashSet, VecDeque},
-    fs::read_to_string,
+    iter::zip,
     num::ParseIntError,
-    time::SystemTime,
+    time::Instant,
     u16,
 };
 
-use anyhow::{Result, anyhow};
-use good_lp::{Expression, Solution, SolverModel, constraint, default_solver, variable, variables};
+use anyhow::Result;
+use good_lp::{
+    Expression, Solution as _, SolverModel, constraint, default_solver, variable, variables,
+};
 use itertools::Itertools;
+use rand::RngExt;
 use regex::Regex;
 use thiserror::Error;
+use tracing::debug;
+
+use crate::{
+    budget::{Timeout, check_budget},
+    generator::InputRng,
+    parsing::{LineError, parse_lines},
+    registry::Day,
+    solution::Solution,
+};
 
 #[cfg(test)]
 mod tests;
 
+/// A button of a machine, toggling the lights (or increasing the joltage counters) it is wired to.
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Button {
-    index: usize,
-    lights_activated: HashSet<usize>,
+pub struct Button {
+    pub index: usize,
+    pub lights_activated: HashSet<usize>,
 }
 
 impl Button {
-    fn new(index: usize, input: &str) -> Result<Self> {
+    /// Parse the comma separated lights of a button, e.g. `1,3`.
+    pub fn new(index: usize, input: &str) -> Result<Self, ParseIntError> {
         let lights_activated = input
             .split(",")
             .map(|number| number.parse::<usize>())
@@ -35,36 +51,69 @@ impl Button {
     }
 }
 
-struct Machine {
-    target_lights: Vec<bool>,
-    buttons: Vec<Button>,
-    target_joltage: Vec<u16>,
+#[derive(Error, Debug)]
+pub enum MachineParsingError {
+    #[error("Expected [lights] (buttons) {{joltage}}")]
+    InvalidFormat,
+    #[error("Invalid light {0}, expected . or #")]
+    InvalidLight(char),
+    #[error("Invalid button")]
+    InvalidButton(#[source] ParseIntError),
+    #[error("Invalid joltage")]
+    InvalidJoltage(#[source] ParseIntError),
+    #[error("Button {button} refers to light {light}, which the machine does not have")]
+    ButtonOutOfRange { button: usize, light: usize },
+    #[error(transparent)]
+    InvalidRegex(#[from] regex::Error),
+}
+
+/// A machine of the factory, written as `[.##.] (3) (1,3) {3,5,4,7}` in the input.
+#[derive(Debug)]
+pub struct Machine {
+    pub target_lights: Vec<bool>,
+    pub buttons: Vec<Button>,
+    pub target_joltage: Vec<u16>,
 }
 
 impl Machine {
-    fn new(input: &str) -> Result<Self> {
+    pub fn new(input: &str) -> Result<Self, MachineParsingError> {
         let regex = Regex::new(
             r"\[(?<target_lights>.+)\] (?<buttons>\([\d, \(\)]*\)) (?<voltage>\{[\d,]*\})",
         )?;
-        let caps = regex.captures(input).expect("The regex to work");
+        let caps = regex
+            .captures(input)
+            .ok_or(MachineParsingError::InvalidFormat)?;
         let target_lights = caps["target_lights"]
             .chars()
             .map(|light| match light {
                 '.' => Ok(false),
                 '#' => Ok(true),
-                _ => Err(anyhow!("Invalid value {}", light.to_string())),
+                _ => Err(MachineParsingError::InvalidLight(light)),
             })
-            .collect::<Result<Vec<bool>>>()?;
+            .collect::<Result<Vec<bool>, MachineParsingError>>()?;
         let buttons = caps["buttons"]
             .split(" ")
             .enumerate()
             .map(|(i, button_str)| Button::new(i, button_str.trim_matches(['(', ')'])))
-            .collect::<Result<Vec<Button>>>()?;
+            .collect::<Result<Vec<Button>, ParseIntError>>()
+            .map_err(MachineParsingError::InvalidButton)?;
         let voltage = caps["voltage"]
             .trim_matches(['{', '}'])
             .split(",")
             .map(|voltage| voltage.parse::<u16>())
-            .collect::<Result<Vec<u16>, ParseIntError>>()?;
+            .collect::<Result<Vec<u16>, ParseIntError>>()
+            .map_err(MachineParsingError::InvalidJoltage)?;
+        // The buttons index both the lights and the joltage counters
+        let n_lights = target_lights.len().min(voltage.len());
+        if let Some((button, light)) = buttons.iter().find_map(|button| {
+            button
+                .lights_activated
+                .iter()
+                .find(|&&light| light >= n_lights)
+                .map(|&light| (button.index, light))
+        }) {
+            return Err(MachineParsingError::ButtonOutOfRange { button, light });
+        }
         Ok(Machine {
             target_lights,
             buttons,
@@ -72,16 +121,19 @@ impl Machine {
         })
     }
 
-    fn find_fastest_way_to_toogle_light_panel(&self) -> Result<Vec<usize>, ToggleSolutionError> {
+    /// Shortest sequence of button indexes turning on the target lights, found with a
+    /// breadth first search from the lights all off. The search stops when the time budget
+    /// of the run is exhausted.
+    pub fn find_fastest_way_to_toogle_light_panel(
+        &self,
+    ) -> Result<Vec<usize>, ToggleSolutionError> {
         // Return the first solution of button push that returns the expected output
         let mut possible_solutions = VecDeque::new();
         possible_solutions.push_front((Vec::new(), vec![false; self.target_lights.len()]));
         let mut explored_positions = HashSet::new();
 
-        // Security to make sure there is no infinite loop
-        let limit: usize = 10_000_000;
-        let mut i = 0;
-        while i < limit {
+        loop {
+            check_budget()?;
             let (button_combination, lights_state) = possible_solutions
                 .pop_front()
                 .ok_or(ToggleSolutionError::EmptySolutionToExplore)?;
@@ -108,18 +160,16 @@ impl Machine {
                     possible_solutions.push_back((new_combination, new_state));
                 };
             }
-            i += 1;
         }
-        Err(ToggleSolutionError::IterationLimitReached(i))
     }
 
     #[allow(dead_code)]
     fn find_joltage_backtrack(&self) -> Result<usize, ToggleSolutionError> {
-        println!("starting {:?}", &self.target_joltage);
+        debug!(target_joltage = ?self.target_joltage, "Starting the backtracking");
         let target_state = vec![0; self.target_joltage.len()];
         let mut maximum_n_buttons = usize::MAX;
         let sorted_buttons = self.sort_buttons_by_joltage_counter_button_number();
-        let start_time = SystemTime::now();
+        let start_time = Instant::now();
 
         let mut explored_states: HashMap<(usize, Vec<u16>), usize> = HashMap::new();
 
@@ -220,10 +270,7 @@ impl Machine {
             &mut explored_states,
         )?
         .ok_or(ToggleSolutionError::EmptySolutionToExplore);
-        println!(
-            "Took {:?}",
-            (SystemTime::now().duration_since(start_time).unwrap())
-        );
+        debug!(elapsed = ?start_time.elapsed(), "Backtracking finished");
         result
     }
 
@@ -261,7 +308,8 @@ impl Machine {
         final_buttons
     }
 
-    fn solve_linear_programming(&self) -> Result<usize> {
+    /// Minimal number of button presses to reach the target joltage.
+    pub fn solve_linear_programming(&self) -> Result<usize> {
         // Give up an use an external library to solve this as a linear programming problem
         let mut vars = variables!();
         let a: Vec<_> = (0..self.buttons.len())
@@ -350,50 +398,106 @@ fn buttons_are_available_for_missing_joltage(
 }
 
 #[derive(Error, Debug)]
-enum ToggleSolutionError {
-    #[error("Maximum iteration of {0} reached")]
-    IterationLimitReached(usize),
+pub enum ToggleSolutionError {
+    #[error(transparent)]
+    Timeout(#[from] Timeout),
     #[error("No more solutions to try")]
     EmptySolutionToExplore,
     // #[error("Got above the maximum joltage")]
     // GotAboveMaximumJoltage,
 }
 
-fn parse_input(input: String) -> Result<Vec<Machine>> {
-    input
-        .lines()
-        .map(Machine::new)
-        .collect::<Result<Vec<Machine>>>()
+pub fn parse_input(input: String) -> Result<Vec<Machine>, LineError<MachineParsingError>> {
+    parse_lines(&input, Machine::new)
 }
 
-// TODO implement width first search with stopping as soon as we hit the target lights
-pub fn main() {
-    let input = parse_input(read_to_string("data/day_10.txt").expect("The data to be readable"))
-        .expect("The input to be correctly parsed");
-    // First problem solution
-    let first_problem_solution: usize = input
-        .iter()
-        .map(|machine| {
-            machine
-                .find_fastest_way_to_toogle_light_panel()
-                .expect("The lights combination to have a solution")
-                .len()
+fn generate_machine(rng: &mut InputRng) -> String {
+    let n_lights = rng.random_range(3..=10);
+    let n_buttons = rng.random_range(n_lights - 1..=n_lights + 3);
+    let mut buttons = (0..n_buttons)
+        .map(|_| {
+            (0..n_lights)
+                .filter(|_| rng.random_bool(0.4))
+                .collect::<Vec<usize>>()
         })
-        .sum();
-    println!(
-        "The minimal number of button presses to get to the correct lights is {}",
-        first_problem_solution
-    );
-    let second_problem_solution: usize = input
-        .into_iter()
-        .map(|machine| {
-            machine
-                .solve_linear_programming()
-                .expect("A solution to exist to find the correct joltage")
+        .collect::<Vec<Vec<usize>>>();
+    // Every button is wired to a light, and every light to a button
+    for button in buttons.iter_mut().filter(|button| button.is_empty()) {
+        button.push(rng.random_range(0..n_lights));
+    }
+    for light in 0..n_lights {
+        if !buttons.iter().any(|button| button.contains(&light)) {
+            let button = &mut buttons[rng.random_range(0..n_buttons)];
+            button.push(light);
+            button.sort();
+        }
+    }
+    // The targets are the result of random presses, so they can be reached
+    let presses = (0..n_buttons)
+        .map(|_| rng.random_range(0..=20))
+        .collect::<Vec<u16>>();
+    let mut lights = vec![false; n_lights];
+    let mut joltage = vec![0; n_lights];
+    for (button, &n_presses) in zip(&buttons, &presses) {
+        for &light in button {
+            lights[light] ^= n_presses % 2 == 1;
+            joltage[light] += n_presses;
+        }
+    }
+    if lights.iter().all(|light| !light) {
+        buttons[0].iter().for_each(|&light| lights[light] = true);
+    }
+    format!(
+        "[{}] {} {{{}}}",
+        lights
+            .iter()
+            .map(|&light| if light { '#' } else { '.' })
+            .collect::<String>(),
+        buttons
+            .iter()
+            .map(|button| format!("({})", button.iter().join(",")))
+            .join(" "),
+        joltage.iter().join(",")
+    )
+}
+
+/// Random machines of up to 10 lights, whose target lights and joltages are the result of
+/// random presses of their buttons. The size is the number of machines.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size).map(|_| generate_machine(rng)).join("\n")
+}
+
+/// Part one sums the minimal presses to turn on the lights, part two to reach the joltages.
+pub struct TenthDay;
+
+impl Solution for TenthDay {
+    type Input = Vec<Machine>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input.to_string())?)
+    }
+
+    // Minimal number of button presses to get to the correct lights
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        input.iter().try_fold(0, |total, machine| {
+            Ok(total + machine.find_fastest_way_to_toogle_light_panel()?.len())
+        })
+    }
+
+    // Minimal number of button presses to get to the correct joltage
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        input.iter().try_fold(0, |total, machine| {
+            Ok(total + machine.solve_linear_programming()?)
         })
-        .sum();
-    println!(
-        "The minimal number of presses to get to the correct joltage is {}",
-        second_problem_solution
-    );
+    }
 }
+
+pub const DAY: Day = Day {
+    number: 10,
+    title: "Factory",
+    solver: &TenthDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
@@ -1,6 +1,16 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, iter::zip, time::Duration};
 
-use crate::tenth_day::{Button, Machine, parse_input};
+use itertools::Itertools;
+use proptest::prelude::*;
+use rand::SeedableRng;
+
+use crate::{
+    budget::with_budget,
+    generator::InputRng,
+    tenth_day::{
+        Button, Machine, MachineParsingError, ToggleSolutionError, generate_input, parse_input,
+    },
+};
 
 static INPUT: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
 [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
@@ -80,3 +90,161 @@ fn test_second_problem_example() {
         .sum();
     assert_eq!(second_problem_solution, 33)
 }
+
+#[test]
+fn test_machine_with_invalid_format_is_rejected() {
+    assert!(Machine::new("[.##.] {3,5,4,7}").is_err())
+}
+
+#[test]
+fn test_light_search_stops_when_the_budget_is_exhausted() {
+    let machine = Machine::new("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")
+        .expect("The machine to be correctly created");
+    let result = with_budget(Some(Duration::ZERO), || {
+        machine.find_fastest_way_to_toogle_light_panel()
+    });
+    assert!(matches!(result, Err(ToggleSolutionError::Timeout(_))))
+}
+
+#[test]
+fn test_unreachable_lights_have_no_solution() {
+    let machine = Machine::new("[#.] (1) {1,1}").expect("The machine to be correctly created");
+    assert!(matches!(
+        machine.find_fastest_way_to_toogle_light_panel(),
+        Err(ToggleSolutionError::EmptySolutionToExplore)
+    ))
+}
+
+// Reference fewest presses to toggle the lights, trying every subset of buttons as pressing
+// a button twice cancels its toggles
+fn fewest_presses_of_all_subsets(machine: &Machine) -> Option<usize> {
+    (0..1usize << machine.buttons.len())
+        .filter(|subset| {
+            let mut lights = vec![false; machine.target_lights.len()];
+            for button in machine
+                .buttons
+                .iter()
+                .filter(|button| subset & (1 << button.index) != 0)
+            {
+                button
+                    .lights_activated
+                    .iter()
+                    .for_each(|&light| lights[light] ^= true);
+            }
+            lights == machine.target_lights
+        })
+        .map(|subset| subset.count_ones() as usize)
+        .min()
+}
+
+// Reference fewest presses to reach the joltage, trying every number of presses of the
+// buttons up to the lowest joltage of their lights
+fn fewest_presses_of_all_combinations(machine: &Machine) -> Option<usize> {
+    machine
+        .buttons
+        .iter()
+        .map(|button| {
+            let maximum_presses = button
+                .lights_activated
+                .iter()
+                .map(|&light| machine.target_joltage[light])
+                .min()
+                .unwrap_or(0);
+            0..=maximum_presses
+        })
+        .multi_cartesian_product()
+        .filter(|presses| {
+            let mut joltage = vec![0; machine.target_joltage.len()];
+            for (button, &n_presses) in zip(&machine.buttons, presses) {
+                button
+                    .lights_activated
+                    .iter()
+                    .for_each(|&light| joltage[light] += n_presses);
+            }
+            joltage == machine.target_joltage
+        })
+        .map(|presses| presses.iter().map(|&n_presses| n_presses as usize).sum())
+        .min()
+}
+
+// Machine small enough for every combination of presses to be tried, its joltage is the
+// result of up to 2 presses of each button
+fn small_machine() -> impl Strategy<Value = Machine> {
+    (1..=4usize)
+        .prop_flat_map(|n_lights| {
+            prop::collection::vec(
+                (
+                    prop::collection::hash_set(0..n_lights, 1..=n_lights),
+                    0..=2u16,
+                ),
+                1..=4,
+            )
+            .prop_map(move |buttons| (n_lights, buttons))
+        })
+        .prop_map(|(n_lights, buttons)| {
+            let mut target_joltage = vec![0; n_lights];
+            for (lights_activated, n_presses) in &buttons {
+                lights_activated
+                    .iter()
+                    .for_each(|&light| target_joltage[light] += n_presses);
+            }
+            Machine {
+                target_lights: target_joltage
+                    .iter()
+                    .map(|joltage| joltage % 2 == 1)
+                    .collect(),
+                buttons: buttons
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (lights_activated, _))| Button {
+                        index,
+                        lights_activated,
+                    })
+                    .collect(),
+                target_joltage,
+            }
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_fastest_toggle_matches_all_subsets(seed in any::<u64>(), size in 1..4usize) {
+        let machines = parse_input(generate_input(&mut InputRng::seed_from_u64(seed), size))
+            .expect("The machines to be parsed");
+        for machine in machines {
+            let presses = machine
+                .find_fastest_way_to_toogle_light_panel()
+                .expect("The lights to be toggled");
+            prop_assert_eq!(Some(presses.len()), fewest_presses_of_all_subsets(&machine));
+        }
+    }
+
+    #[test]
+    fn test_lp_solver_matches_all_combinations(machine in small_machine()) {
+        let presses = machine
+            .solve_linear_programming()
+            .expect("The joltage to be reached");
+        prop_assert_eq!(Some(presses), fewest_presses_of_all_combinations(&machine));
+    }
+}
+
+#[test]
+fn test_button_out_of_range_is_rejected() {
+    assert!(matches!(
+        Machine::new("[.#] (5) {1,2}"),
+        Err(MachineParsingError::ButtonOutOfRange {
+            button: 0,
+            light: 5
+        })
+    ));
+    // The button is on the lights but not on the joltage counters
+    assert!(matches!(
+        Machine::new("[.##] (0) (2) {1,2}"),
+        Err(MachineParsingError::ButtonOutOfRange {
+            button: 1,
+            light: 2
+        })
+    ));
+}
@@ -1,4 +1,10 @@
-use std::fs;
+//! Day 3: Lobby, turning on the batteries of power banks to get the highest joltage.
+
+use anyhow::Result;
+use rand::RngExt;
+use thiserror::Error;
+
+use crate::{generator::InputRng, parsing::parse_lines, registry::Day, solution::Solution};
 
 #[cfg(test)]
 mod tests;
@@ -29,27 +35,57 @@ impl PartialOrd for Battery {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum PowerBankParsingError {
+    #[error("Invalid battery joltage {0}, expected a digit")]
+    InvalidJoltage(char),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum JoltageError {
+    #[error(
+        "Cannot turn on {n_active_batteries} batteries of bank {bank}, it only has {n_batteries}"
+    )]
+    NotEnoughBatteries {
+        bank: usize,
+        n_batteries: usize,
+        n_active_batteries: usize,
+    },
+}
+
+/// A bank of batteries, written as a line of joltage digits in the input.
 #[derive(Debug, Clone)]
-struct PowerBank {
+pub struct PowerBank {
     batteries: Vec<Battery>,
 }
 
 impl PowerBank {
-    fn new(input: &str) -> Self {
-        PowerBank {
+    /// Parse a line of joltage digits, e.g. `987654321111111`.
+    pub fn new(input: &str) -> Result<Self, PowerBankParsingError> {
+        Ok(PowerBank {
             batteries: input
                 .chars()
                 .enumerate()
-                .map(|(index, battery)| Battery {
-                    position: index,
-                    joltage: battery.to_string().parse::<usize>().unwrap(),
+                .map(|(index, battery)| {
+                    Ok(Battery {
+                        position: index,
+                        joltage: battery
+                            .to_digit(10)
+                            .ok_or(PowerBankParsingError::InvalidJoltage(battery))?
+                            as usize,
+                    })
                 })
-                .collect::<Vec<Battery>>(),
-        }
+                .collect::<Result<Vec<Battery>, PowerBankParsingError>>()?,
+        })
     }
 
-    fn get_maximum_joltage(&self, n_active_batteries: usize) -> usize {
+    /// Highest joltage obtained by turning on exactly n batteries, keeping their order,
+    /// `None` if the bank has less than n batteries.
+    pub fn get_maximum_joltage(&self, n_active_batteries: usize) -> Option<usize> {
         let n_batteries = self.batteries.len();
+        if n_batteries < n_active_batteries {
+            return None;
+        }
 
         let ten: usize = 10;
         let mut active_joltage = 0;
@@ -64,24 +100,67 @@ impl PowerBank {
                 best_available_battery.joltage * ten.pow((n_active_batteries - i) as u32);
             last_active_battery_position = best_available_battery.position + 1;
         }
-        active_joltage
+        Some(active_joltage)
     }
 }
 
-pub fn main() {
-    let power_banks = fs::read_to_string("data/day_3.txt")
-        .expect("The input to be correctly read")
-        .lines()
-        .map(PowerBank::new)
-        .collect::<Vec<PowerBank>>();
-    let first_output_joltage = power_banks
-        .iter()
-        .map(|power_bank| power_bank.get_maximum_joltage(2))
-        .sum::<usize>();
-    dbg!(first_output_joltage);
-    let unsafe_output_joltage = power_banks
+/// Sum of the maximum joltage of every bank, an error if a bank has less than n batteries.
+pub fn get_total_joltage(
+    power_banks: &[PowerBank],
+    n_active_batteries: usize,
+) -> Result<usize, JoltageError> {
+    power_banks
         .iter()
-        .map(|power_bank| power_bank.get_maximum_joltage(12))
-        .sum::<usize>();
-    dbg!(unsafe_output_joltage);
+        .enumerate()
+        .map(|(index, power_bank)| {
+            power_bank.get_maximum_joltage(n_active_batteries).ok_or(
+                JoltageError::NotEnoughBatteries {
+                    bank: index + 1,
+                    n_batteries: power_bank.batteries.len(),
+                    n_active_batteries,
+                },
+            )
+        })
+        .sum()
+}
+
+/// Random banks of 100 batteries, the size is the number of banks.
+pub fn generate_input(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..100)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
 }
+
+/// Part one turns on 2 batteries per bank, part two 12.
+pub struct ThirdDay;
+
+impl Solution for ThirdDay {
+    type Input = Vec<PowerBank>;
+    type FirstAnswer = usize;
+    type SecondAnswer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, PowerBank::new)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<usize> {
+        Ok(get_total_joltage(input, 2)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<usize> {
+        Ok(get_total_joltage(input, 12)?)
+    }
+}
+
+pub const DAY: Day = Day {
+    number: 3,
+    title: "Lobby",
+    solver: &ThirdDay,
+    generator: Some(generate_input),
+    visualizer: None,
+};
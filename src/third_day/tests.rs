@@ -1,4 +1,11 @@
-use crate::third_day::Battery;
+use itertools::Itertools;
+use proptest::prelude::*;
+use test_case::test_case;
+
+use crate::{
+    solution::Solution,
+    third_day::{Battery, JoltageError, PowerBank, ThirdDay, get_total_joltage},
+};
 
 #[test]
 fn test_battery_ordering() {
@@ -38,3 +45,55 @@ fn test_battery_with_same_joltage_first_one_is_greater() {
     };
     assert!(first_battery > second_battery)
 }
+
+#[test]
+fn test_power_bank_with_invalid_joltage_is_rejected() {
+    assert!(PowerBank::new("12a4").is_err())
+}
+
+// Reference joltage trying every combination of n batteries, in the order of the bank
+fn maximum_joltage_of_all_combinations(bank: &str, n_active_batteries: usize) -> usize {
+    bank.chars()
+        .combinations(n_active_batteries)
+        .map(|batteries| {
+            batteries
+                .into_iter()
+                .collect::<String>()
+                .parse::<usize>()
+                .expect("The batteries to be digits")
+        })
+        .max()
+        .expect("A combination to be found")
+}
+
+proptest! {
+    #[test]
+    fn test_maximum_joltage_matches_all_combinations(
+        (bank, n_active_batteries) in "[1-9]{1,12}".prop_flat_map(|bank| {
+            let n_batteries = bank.len();
+            (Just(bank), 1..=n_batteries)
+        })
+    ) {
+        let power_bank = PowerBank::new(&bank).expect("The bank to be parsed");
+        prop_assert_eq!(
+            power_bank.get_maximum_joltage(n_active_batteries),
+            Some(maximum_joltage_of_all_combinations(&bank, n_active_batteries))
+        );
+    }
+}
+
+#[test_case("", 0; "empty bank")]
+#[test_case("12345678901", 11; "bank shorter than the active batteries")]
+fn test_bank_with_not_enough_batteries_is_an_error(bank: &str, n_batteries: usize) {
+    let power_banks =
+        ThirdDay::parse(&format!("{}\n987654321111111", bank)).expect("The banks to be parsed");
+    assert_eq!(
+        get_total_joltage(&power_banks, 12),
+        Err(JoltageError::NotEnoughBatteries {
+            bank: 1,
+            n_batteries,
+            n_active_batteries: 12,
+        })
+    );
+    assert!(ThirdDay::part_two(&power_banks).is_err());
+}
@@ -0,0 +1,148 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::info_span;
+
+use crate::{registry::Day, solution::Answer};
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "data/answers.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct ExpectedAnswers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// Known good answers, stored per day in tables named day_<number>:
+/// ```toml
+/// [day_1]
+/// part_one = 1234
+/// part_two = 5678
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct AnswersFile {
+    #[serde(flatten)]
+    days: BTreeMap<String, ExpectedAnswers>,
+}
+
+impl AnswersFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read the answers file {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&ExpectedAnswers> {
+        self.days.get(&format!("day_{}", day))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer, actual: Answer },
+    // The day was solved but there is no known answer to compare it with
+    Missing(Answer),
+    Error(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {}, got {})", expected, actual)
+            }
+            Verdict::Missing(actual) => write!(f, "missing (got {})", actual),
+            Verdict::Error(error) => write!(f, "ERROR ({})", error),
+        }
+    }
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+
+    fn new(expected: Option<&Answer>, actual: Result<Answer>) -> Self {
+        match (expected, actual) {
+            (_, Err(error)) => Verdict::Error(format!("{:#}", error)),
+            (None, Ok(actual)) => Verdict::Missing(actual),
+            (Some(expected), Ok(actual)) if *expected == actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayVerification {
+    pub day: u8,
+    pub part_one: Verdict,
+    pub part_two: Verdict,
+}
+
+impl DayVerification {
+    pub fn is_failure(&self) -> bool {
+        self.part_one.is_failure() || self.part_two.is_failure()
+    }
+}
+
+/// Compare the answers of a day on the given input with the expected ones.
+/// An input that could not be read or parsed is reported as an error of both parts,
+/// otherwise each part is verified even when the other one fails.
+pub fn verify_day(
+    day: &Day,
+    input: Result<String>,
+    expected: Option<&ExpectedAnswers>,
+) -> DayVerification {
+    let expected = expected.cloned().unwrap_or_default();
+    let _span = info_span!("day", number = day.number).entered();
+    match input.and_then(|input| day.solver.parse(&input)) {
+        Ok(parsed_input) => DayVerification {
+            day: day.number,
+            part_one: Verdict::new(
+                expected.part_one.as_ref(),
+                day.solver.part_one(&parsed_input),
+            ),
+            part_two: Verdict::new(
+                expected.part_two.as_ref(),
+                day.solver.part_two(&parsed_input),
+            ),
+        },
+        Err(error) => DayVerification {
+            day: day.number,
+            part_one: Verdict::Error(format!("{:#}", error)),
+            part_two: Verdict::Error(format!("{:#}", error)),
+        },
+    }
+}
+
+pub fn format_report(verifications: &[DayVerification]) -> String {
+    verifications
+        .iter()
+        .flat_map(|verification| {
+            [
+                format!(
+                    "Day {} - part 1: {}",
+                    verification.day, verification.part_one
+                ),
+                format!(
+                    "Day {} - part 2: {}",
+                    verification.day, verification.part_two
+                ),
+            ]
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,100 @@
+use anyhow::anyhow;
+
+use crate::{
+    registry::get_day,
+    solution::Answer,
+    verification::{AnswersFile, ExpectedAnswers, Verdict, verify_day},
+};
+
+static FIRST_DAY_INPUT: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+#[test]
+fn test_parse_answers_file() {
+    let answers = AnswersFile::parse(
+        "[day_1]
+part_one = 3
+part_two = 6
+
+[day_7]
+part_one = \"abc\"
+",
+    )
+    .expect("The answers file to be valid");
+    assert_eq!(
+        answers.get(1),
+        Some(&ExpectedAnswers {
+            part_one: Some(Answer::Number(3)),
+            part_two: Some(Answer::Number(6)),
+        })
+    );
+    assert_eq!(
+        answers.get(7),
+        Some(&ExpectedAnswers {
+            part_one: Some(Answer::Text(String::from("abc"))),
+            part_two: None,
+        })
+    );
+    assert_eq!(answers.get(2), None);
+}
+
+#[test]
+fn test_verify_day_pass_and_fail() {
+    let day = get_day(1).expect("The first day to be registered");
+    let expected = ExpectedAnswers {
+        part_one: Some(Answer::Number(3)),
+        part_two: Some(Answer::Number(7)),
+    };
+    let verification = verify_day(day, Ok(FIRST_DAY_INPUT.to_string()), Some(&expected));
+    assert_eq!(verification.part_one, Verdict::Pass);
+    assert_eq!(
+        verification.part_two,
+        Verdict::Fail {
+            expected: Answer::Number(7),
+            actual: Answer::Number(6)
+        }
+    );
+    assert!(verification.is_failure());
+}
+
+#[test]
+fn test_verify_day_without_expected_answers() {
+    let day = get_day(1).expect("The first day to be registered");
+    let verification = verify_day(day, Ok(FIRST_DAY_INPUT.to_string()), None);
+    assert_eq!(verification.part_one, Verdict::Missing(Answer::Number(3)));
+    assert_eq!(verification.part_two, Verdict::Missing(Answer::Number(6)));
+    assert!(!verification.is_failure());
+}
+
+#[test]
+fn test_verify_day_reports_each_part() {
+    // The bank has enough batteries for the first part, but not for the second one
+    let day = get_day(3).expect("The third day to be registered");
+    let expected = ExpectedAnswers {
+        part_one: Some(Answer::Number(12)),
+        part_two: None,
+    };
+    let verification = verify_day(day, Ok(String::from("12\n")), Some(&expected));
+    assert_eq!(verification.part_one, Verdict::Pass);
+    assert!(matches!(verification.part_two, Verdict::Error(_)));
+    assert!(verification.is_failure());
+}
+
+#[test]
+fn test_verify_day_with_unreadable_input() {
+    let day = get_day(1).expect("The first day to be registered");
+    let verification = verify_day(day, Err(anyhow!("missing input")), None);
+    assert_eq!(
+        verification.part_one,
+        Verdict::Error(String::from("missing input"))
+    );
+    assert!(verification.is_failure());
+}
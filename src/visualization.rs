@@ -0,0 +1,390 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result, anyhow};
+use clap::ValueEnum;
+
+use crate::registry::Day;
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_VISUALIZATION_DIRECTORY: &str = "visualizations";
+pub const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(200);
+/// Number of columns of a picture drawn in the terminal, larger pictures are scaled down.
+pub const MAXIMUM_TERMINAL_WIDTH: usize = 120;
+/// Size in cells of the pictures of the days whose coordinates are scaled down to fit them.
+pub const CANVAS_WIDTH: usize = 120;
+pub const CANVAS_HEIGHT: usize = 60;
+// Width in pixels of a picture drawn in SVG, the height keeps the ratio of the picture
+const SVG_WIDTH: usize = 800;
+// Moves the cursor back to the top left corner of a cleared terminal, to play the frames
+pub const CLEAR_TERMINAL: &str = "\x1b[2J\x1b[H";
+
+/// Where a visualization is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Renderer {
+    /// Characters printed in the terminal, colored with ANSI codes
+    Terminal,
+    /// SVG files, one per frame
+    Svg,
+}
+
+/// Color of a shape, an ANSI color in the terminal and a named color in SVG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    const PALETTE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// Color of the n-th group of a picture, the colors are reused past the size of the palette.
+    pub fn of_index(index: usize) -> Self {
+        Self::PALETTE[index % Self::PALETTE.len()]
+    }
+
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Gray => 90,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+
+    fn svg_name(&self) -> &'static str {
+        match self {
+            Color::Gray => "gray",
+            Color::Red => "crimson",
+            Color::Green => "seagreen",
+            Color::Yellow => "goldenrod",
+            Color::Blue => "royalblue",
+            Color::Magenta => "darkmagenta",
+            Color::Cyan => "darkcyan",
+        }
+    }
+}
+
+/// Coordinates of a cell of a picture, `y` grows downwards.
+pub type Point = (usize, usize);
+
+/// Element of a picture, the shapes are drawn in order so the last ones are on top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// A single cell, drawn with its character in the terminal
+    Cell {
+        at: Point,
+        glyph: char,
+        color: Color,
+    },
+    /// A straight segment between two cells
+    Line {
+        from: Point,
+        to: Point,
+        color: Color,
+    },
+    /// A closed outline going through the points, filled in SVG
+    Polygon { points: Vec<Point>, color: Color },
+    /// The outline of the axis aligned rectangle between two opposite corners
+    Rectangle {
+        corner_a: Point,
+        corner_b: Point,
+        color: Color,
+    },
+}
+
+/// State of a day drawn on a plane of `width` by `height` cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub title: String,
+    pub width: usize,
+    pub height: usize,
+    pub shapes: Vec<Shape>,
+}
+
+impl Picture {
+    pub fn new(title: impl Into<String>, width: usize, height: usize) -> Self {
+        Picture {
+            title: title.into(),
+            width,
+            height,
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape)
+    }
+}
+
+/// Frames of a day drawn from its input, a single frame being a picture of its final state.
+pub type Visualizer = fn(&str) -> Result<Vec<Picture>>;
+
+/// Draw the input of the day with its visualizer.
+pub fn visualize(day: &Day, input: &str) -> Result<Vec<Picture>> {
+    let visualizer = day
+        .visualizer
+        .ok_or_else(|| anyhow!("Day {} does not have a visualization", day.number))?;
+    visualizer(input)
+}
+
+/// Scale points whose coordinates go up to the largest ones of the points into a canvas of
+/// `width` by `height` cells, for the days whose coordinates are too large to be drawn as is.
+pub fn fit_to_canvas(points: &[Point], width: usize, height: usize) -> Vec<Point> {
+    let maximum_x = points.iter().map(|&(x, _)| x).max().unwrap_or(0).max(1);
+    let maximum_y = points.iter().map(|&(_, y)| y).max().unwrap_or(0).max(1);
+    points
+        .iter()
+        .map(|&(x, y)| {
+            (
+                x * (width.max(1) - 1) / maximum_x,
+                y * (height.max(1) - 1) / maximum_y,
+            )
+        })
+        .collect()
+}
+
+// Cells crossed by the segment, from one end to the other
+fn segment_cells(from: Point, to: Point) -> impl Iterator<Item = Point> {
+    let n_steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1));
+    let interpolate = move |start: usize, end: usize, step: usize| {
+        if n_steps == 0 {
+            start
+        } else if end >= start {
+            start + (end - start) * step / n_steps
+        } else {
+            start - (start - end) * step / n_steps
+        }
+    };
+    (0..=n_steps).map(move |step| {
+        (
+            interpolate(from.0, to.0, step),
+            interpolate(from.1, to.1, step),
+        )
+    })
+}
+
+fn segment_glyph(from: Point, to: Point) -> char {
+    if from.1 == to.1 {
+        '-'
+    } else if from.0 == to.0 {
+        '|'
+    } else if (from.0 < to.0) == (from.1 < to.1) {
+        '\\'
+    } else {
+        '/'
+    }
+}
+
+// Character grid of the picture, scaled down to fit in the terminal
+struct TerminalCanvas {
+    scale: usize,
+    cells: Vec<Vec<Option<(char, Color)>>>,
+}
+
+impl TerminalCanvas {
+    fn new(picture: &Picture) -> Self {
+        let scale = picture.width.div_ceil(MAXIMUM_TERMINAL_WIDTH).max(1);
+        TerminalCanvas {
+            scale,
+            cells: vec![vec![None; picture.width.div_ceil(scale)]; picture.height.div_ceil(scale)],
+        }
+    }
+
+    fn put(&mut self, (x, y): Point, glyph: char, color: Color) {
+        if let Some(cell) = self
+            .cells
+            .get_mut(y / self.scale)
+            .and_then(|row| row.get_mut(x / self.scale))
+        {
+            *cell = Some((glyph, color));
+        }
+    }
+
+    fn draw_segment(&mut self, from: Point, to: Point, color: Color) {
+        let scaled = |(x, y): Point| (x / self.scale, y / self.scale);
+        let glyph = segment_glyph(scaled(from), scaled(to));
+        for cell in segment_cells(scaled(from), scaled(to)) {
+            self.put((cell.0 * self.scale, cell.1 * self.scale), glyph, color);
+        }
+    }
+}
+
+/// Draw the picture with characters, colored with ANSI codes unless `colored` is false.
+pub fn render_terminal(picture: &Picture, colored: bool) -> String {
+    let mut canvas = TerminalCanvas::new(picture);
+    for shape in &picture.shapes {
+        match shape {
+            Shape::Cell { at, glyph, color } => canvas.put(*at, *glyph, *color),
+            Shape::Line { from, to, color } => canvas.draw_segment(*from, *to, *color),
+            Shape::Polygon { points, color } => {
+                for (index, &from) in points.iter().enumerate() {
+                    canvas.draw_segment(from, points[(index + 1) % points.len()], *color);
+                }
+            }
+            Shape::Rectangle {
+                corner_a,
+                corner_b,
+                color,
+            } => {
+                let corners = [
+                    *corner_a,
+                    (corner_b.0, corner_a.1),
+                    *corner_b,
+                    (corner_a.0, corner_b.1),
+                ];
+                for index in 0..corners.len() {
+                    canvas.draw_segment(corners[index], corners[(index + 1) % 4], *color);
+                }
+            }
+        }
+    }
+
+    let mut lines = vec![picture.title.clone()];
+    for row in canvas.cells {
+        let mut line = String::new();
+        let mut current_color = None;
+        for cell in row {
+            let (glyph, color) = match cell {
+                Some((glyph, color)) => (glyph, Some(color)),
+                None => (' ', None),
+            };
+            if colored && color.is_some() && color != current_color {
+                let code = color.map_or(0, |color| color.ansi_code());
+                let _ = write!(line, "\x1b[{}m", code);
+                current_color = color;
+            }
+            line.push(glyph);
+        }
+        if current_color.is_some() {
+            line.push_str("\x1b[0m");
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+// Text of an SVG element, with the characters starting markup or entities escaped
+fn escape_svg_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Draw the picture as an SVG document, each cell being a square of side 1.
+pub fn render_svg(picture: &Picture) -> String {
+    // The lines go through the centers of the cells
+    let center = |(x, y): Point| (x as f64 + 0.5, y as f64 + 0.5);
+    let (width, height) = (picture.width.max(1), picture.height.max(1));
+    let mut document = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}" width="{}" height="{}">"#,
+        width,
+        height,
+        SVG_WIDTH,
+        SVG_WIDTH * height / width,
+    );
+    let _ = write!(
+        document,
+        "\n<title>{}</title>\n<rect width=\"100%\" height=\"100%\" fill=\"white\"/>",
+        escape_svg_text(&picture.title)
+    );
+    for shape in &picture.shapes {
+        document.push('\n');
+        let _ = match shape {
+            Shape::Cell { at, color, .. } => write!(
+                document,
+                r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#,
+                at.0,
+                at.1,
+                color.svg_name()
+            ),
+            Shape::Line { from, to, color } => {
+                let (from, to) = (center(*from), center(*to));
+                write!(
+                    document,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+                    from.0,
+                    from.1,
+                    to.0,
+                    to.1,
+                    color.svg_name()
+                )
+            }
+            Shape::Polygon { points, color } => write!(
+                document,
+                r#"<polygon points="{}" fill="{}" fill-opacity="0.3" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"/>"#,
+                points
+                    .iter()
+                    .map(|&point| {
+                        let (x, y) = center(point);
+                        format!("{},{}", x, y)
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                color.svg_name(),
+                color.svg_name()
+            ),
+            Shape::Rectangle {
+                corner_a,
+                corner_b,
+                color,
+            } => write!(
+                document,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="{}" stroke-width="2" vector-effect="non-scaling-stroke"/>"#,
+                corner_a.0.min(corner_b.0),
+                corner_a.1.min(corner_b.1),
+                corner_a.0.abs_diff(corner_b.0) + 1,
+                corner_a.1.abs_diff(corner_b.1) + 1,
+                color.svg_name()
+            ),
+        };
+    }
+    document.push_str("\n</svg>\n");
+    document
+}
+
+/// Write every frame to its own SVG file of the directory, named after the day and the
+/// number of the frame, and return the paths of the files.
+pub fn write_svg_frames(
+    pictures: &[Picture],
+    directory: &Path,
+    day_number: u8,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory).with_context(|| {
+        format!(
+            "Could not create the visualization directory {}",
+            directory.display()
+        )
+    })?;
+    pictures
+        .iter()
+        .enumerate()
+        .map(|(index, picture)| {
+            let path = directory.join(format!("day_{}_frame_{:03}.svg", day_number, index + 1));
+            fs::write(&path, render_svg(picture))
+                .with_context(|| format!("Could not write the frame {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -0,0 +1,161 @@
+use std::{env::temp_dir, fs};
+
+use crate::{
+    registry::get_day,
+    visualization::{
+        Color, Picture, Shape, fit_to_canvas, render_svg, render_terminal, visualize,
+        write_svg_frames,
+    },
+};
+
+fn small_picture() -> Picture {
+    let mut picture = Picture::new("Small", 5, 3);
+    picture.push(Shape::Line {
+        from: (0, 1),
+        to: (4, 1),
+        color: Color::Gray,
+    });
+    picture.push(Shape::Cell {
+        at: (2, 1),
+        glyph: '@',
+        color: Color::Red,
+    });
+    picture.push(Shape::Line {
+        from: (4, 0),
+        to: (4, 2),
+        color: Color::Blue,
+    });
+    picture
+}
+
+#[test]
+fn test_render_terminal() {
+    assert_eq!(
+        render_terminal(&small_picture(), false),
+        "Small\n    |\n--@-|\n    |"
+    )
+}
+
+#[test]
+fn test_render_terminal_with_colors() {
+    let mut picture = Picture::new("Colored", 2, 1);
+    picture.push(Shape::Cell {
+        at: (0, 0),
+        glyph: '#',
+        color: Color::Red,
+    });
+    picture.push(Shape::Cell {
+        at: (1, 0),
+        glyph: '#',
+        color: Color::Green,
+    });
+    assert_eq!(
+        render_terminal(&picture, true),
+        "Colored\n\x1b[31m#\x1b[32m#\x1b[0m"
+    )
+}
+
+#[test]
+fn test_render_terminal_scales_down_wide_pictures() {
+    let mut picture = Picture::new("Wide", 1000, 10);
+    picture.push(Shape::Rectangle {
+        corner_a: (0, 0),
+        corner_b: (999, 9),
+        color: Color::Yellow,
+    });
+    let rendered = render_terminal(&picture, false);
+    let rows = rendered.lines().skip(1).collect::<Vec<&str>>();
+    // 9 cells per character, the left edge being drawn last
+    assert_eq!(
+        rows,
+        vec![
+            format!("|{}|", "-".repeat(110)),
+            format!("|{}", "-".repeat(111))
+        ]
+    );
+}
+
+#[test]
+fn test_render_svg() {
+    let mut picture = small_picture();
+    picture.push(Shape::Polygon {
+        points: vec![(0, 0), (4, 0), (4, 2)],
+        color: Color::Green,
+    });
+    let svg = render_svg(&picture);
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 5 3""#));
+    assert!(svg.contains(r#"<rect x="2" y="1" width="1" height="1" fill="crimson"/>"#));
+    assert!(svg.contains(r#"<line x1="0.5" y1="1.5" x2="4.5" y2="1.5" stroke="gray""#));
+    assert!(svg.contains(r#"<polygon points="0.5,0.5 4.5,0.5 4.5,2.5" fill="seagreen""#));
+    assert!(svg.ends_with("</svg>\n"));
+}
+
+#[test]
+fn test_render_svg_escapes_the_title() {
+    let picture = Picture::new("Rolls <removed> & counted", 5, 3);
+    assert!(render_svg(&picture).contains("<title>Rolls &lt;removed&gt; &amp; counted</title>"));
+}
+
+#[test]
+fn test_fit_to_canvas() {
+    let points = fit_to_canvas(&[(0, 0), (50_000, 1_000), (100_000, 2_000)], 11, 5);
+    assert_eq!(points, vec![(0, 0), (5, 2), (10, 4)])
+}
+
+#[test]
+fn test_write_svg_frames() {
+    let directory = temp_dir().join("advent_of_code_2025_visualization_test");
+    let _ = fs::remove_dir_all(&directory);
+    let paths = write_svg_frames(&[small_picture(), small_picture()], &directory, 4)
+        .expect("The frames to be written");
+    let first_frame = fs::read_to_string(&paths[0]).expect("The first frame to be read");
+    fs::remove_dir_all(&directory).expect("The visualization directory to be removed");
+
+    assert_eq!(
+        paths,
+        vec![
+            directory.join("day_4_frame_001.svg"),
+            directory.join("day_4_frame_002.svg")
+        ]
+    );
+    assert_eq!(first_frame, render_svg(&small_picture()));
+}
+
+#[test]
+fn test_day_without_visualization() {
+    let day = get_day(1).expect("The day to be registered");
+    assert!(visualize(day, "L68").is_err())
+}
+
+#[test]
+fn test_roll_removal_frames() {
+    let day = get_day(4).expect("The day to be registered");
+    let frames = visualize(day, "@@@\n@@@\n@@@").expect("The rolls to be drawn");
+    // The corners are removed first, then the edges and the center last
+    assert_eq!(
+        frames
+            .iter()
+            .map(|frame| render_terminal(frame, false))
+            .collect::<Vec<String>>(),
+        vec![
+            "Round 1: 4 accessible rolls, 0 removed\n@@@\n@@@\n@@@",
+            "Round 2: 4 accessible rolls, 4 removed\n.@.\n@@@\n.@.",
+            "Round 3: 1 accessible rolls, 8 removed\n...\n.@.\n...",
+            "Round 4: 0 accessible rolls, 9 removed\n...\n...\n...",
+        ]
+    )
+}
+
+#[test]
+fn test_tachyon_beams_picture() {
+    let day = get_day(7).expect("The day to be registered");
+    let frames =
+        visualize(day, "..S..\n.....\n..^..\n.^...\n.....").expect("The beams to be drawn");
+    assert_eq!(
+        frames
+            .iter()
+            .map(|frame| render_terminal(frame, false))
+            .collect::<Vec<String>>(),
+        vec!["2 splits\n  S\n  |\n |^|\n|^||\n| ||"]
+    )
+}
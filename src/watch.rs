@@ -0,0 +1,87 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::report::RunReport;
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Detects the changes of a set of files by polling their modification times,
+/// a file created or removed since the previous poll counts as changed.
+#[derive(Debug)]
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl FileWatcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        FileWatcher {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let modified = modified_time(&path);
+                    (path, modified)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Files changed since the previous call, or since the watcher was created.
+    pub fn changed_files(&mut self) -> Vec<PathBuf> {
+        let mut changed_files = Vec::new();
+        for (path, modified) in self.files.iter_mut() {
+            let current_modified = modified_time(path);
+            if current_modified != *modified {
+                *modified = current_modified;
+                changed_files.push(path.clone());
+            }
+        }
+        changed_files
+    }
+}
+
+/// Answers and timings of a run, each answer is compared with the one of the previous run.
+pub fn format_changes(previous: Option<&RunReport>, current: &RunReport) -> String {
+    let Some(parse_duration_ns) = current.parse_duration_ns else {
+        return current.to_text();
+    };
+    let mut lines = vec![format!(
+        "Day {} - parse: {:.2?}",
+        current.day,
+        Duration::from_nanos(parse_duration_ns)
+    )];
+    for part in &current.parts {
+        let previous_answer = previous
+            .and_then(|previous| {
+                previous
+                    .parts
+                    .iter()
+                    .find(|previous_part| previous_part.part == part.part)
+            })
+            .and_then(|previous_part| previous_part.answer.as_ref());
+        let line = current.part_to_text(part);
+        lines.push(match (previous_answer, &part.answer) {
+            (Some(previous_answer), Some(answer)) if previous_answer == answer => {
+                format!("{}, unchanged", line)
+            }
+            (Some(previous_answer), _) => format!("{}, was {}", line, previous_answer),
+            (None, _) => line,
+        });
+    }
+    lines.join("\n")
+}
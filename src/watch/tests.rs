@@ -0,0 +1,94 @@
+use std::{
+    env::temp_dir,
+    fs::{self, File},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    report::{PartReport, RunReport},
+    solution::Answer,
+    watch::{FileWatcher, format_changes},
+};
+
+fn report(answers: [Option<usize>; 2]) -> RunReport {
+    RunReport {
+        day: 3,
+        input: String::from("data/day_3.txt"),
+        parse_duration_ns: Some(2_000),
+        error: None,
+        parts: answers
+            .iter()
+            .zip(1..)
+            .map(|(answer, part)| PartReport {
+                part,
+                answer: answer.map(Answer::Number),
+                duration_ns: 1_000,
+                error: answer.is_none().then(|| String::from("no answer")),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_changed_files() {
+    let directory = temp_dir().join("advent_of_code_2025_watch_test");
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).expect("The watched directory to be created");
+    let input_path = directory.join("day_1.txt");
+    let example_path = directory.join("example.txt");
+    fs::write(&input_path, "L68").expect("The input to be written");
+    let mut watcher = FileWatcher::new(vec![input_path.clone(), example_path.clone()]);
+
+    let unchanged_files = watcher.changed_files();
+    File::options()
+        .write(true)
+        .open(&input_path)
+        .and_then(|file| file.set_modified(SystemTime::now() + Duration::from_secs(10)))
+        .expect("The input to be modified");
+    let modified_files = watcher.changed_files();
+    fs::write(&example_path, "R48").expect("The example to be created");
+    let created_files = watcher.changed_files();
+    fs::remove_dir_all(&directory).expect("The watched directory to be removed");
+    let removed_files = watcher.changed_files();
+
+    assert!(unchanged_files.is_empty());
+    assert_eq!(modified_files, vec![input_path.clone()]);
+    assert_eq!(created_files, vec![example_path.clone()]);
+    assert_eq!(removed_files, vec![input_path, example_path]);
+}
+
+#[test]
+fn test_first_run_has_no_changes() {
+    assert_eq!(
+        format_changes(None, &report([Some(357), Some(3121)])),
+        "Day 3 - parse: 2.00µs
+Day 3 - part 1: 357 (1.00µs)
+Day 3 - part 2: 3121 (1.00µs)"
+    )
+}
+
+#[test]
+fn test_changes_from_previous_run() {
+    assert_eq!(
+        format_changes(
+            Some(&report([Some(357), Some(3000)])),
+            &report([Some(357), Some(3121)])
+        ),
+        "Day 3 - parse: 2.00µs
+Day 3 - part 1: 357 (1.00µs), unchanged
+Day 3 - part 2: 3121 (1.00µs), was 3000"
+    )
+}
+
+#[test]
+fn test_failed_part_shows_previous_answer() {
+    assert_eq!(
+        format_changes(
+            Some(&report([Some(357), Some(3121)])),
+            &report([Some(357), None])
+        ),
+        "Day 3 - parse: 2.00µs
+Day 3 - part 1: 357 (1.00µs), unchanged
+Day 3 - part 2 failed: no answer, was 3121"
+    )
+}
@@ -0,0 +1,25 @@
+// Runs every example of examples/day_<number>/ against its registered day, one test per
+// example, so adding an example only requires adding its input and expected answers.
+use std::path::Path;
+
+use advent_of_code_2025::{
+    examples::{DEFAULT_EXAMPLES_DIRECTORY, check_example, discover_examples},
+    registry::DAYS,
+};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() -> Result<(), Failed> {
+    let arguments = Arguments::from_args();
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_EXAMPLES_DIRECTORY);
+    let mut trials = Vec::new();
+    for day in DAYS {
+        let examples =
+            discover_examples(&directory, day.number).map_err(|error| format!("{:#}", error))?;
+        trials.extend(examples.into_iter().map(|example| {
+            Trial::test(format!("day_{}::{}", day.number, example.name), move || {
+                check_example(day, &example).map_err(|error| format!("{:#}", error).into())
+            })
+        }));
+    }
+    libtest_mimic::run(&arguments, trials).exit()
+}